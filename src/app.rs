@@ -11,7 +11,10 @@ use tower::{
     web_model::MenuData,
 };
 
-use crate::page::{ConfigPane, DashboardPane, DictPane, UserPane};
+use crate::page::{
+    ApprovalPane, AuditPane, ConfigPane, ConfigSearchPane, ConfigTemplatePane, DashboardPane,
+    DictPane, FlagPane, UserPane,
+};
 
 #[component]
 pub fn App() -> impl IntoView {
@@ -30,7 +33,12 @@ pub fn App() -> impl IntoView {
                         <Route path=path!("/") view=DashboardPane />
                         <Route path=path!("/user") view=UserPane />
                         <Route path=path!("/config") view=ConfigPane />
+                        <Route path=path!("/config/search") view=ConfigSearchPane />
+                        <Route path=path!("/config/template") view=ConfigTemplatePane />
+                        <Route path=path!("/flag") view=FlagPane />
+                        <Route path=path!("/approval") view=ApprovalPane />
                         <Route path=path!("/dict") view=DictPane />
+                        <Route path=path!("/audit") view=AuditPane />
                     </Routes>
                 </div>
             </div>
@@ -47,10 +55,21 @@ fn get_menus() -> MenuData {
             "配置管理",
             icondata::AiProjectOutlined,
         ))
+        .add_child_menu(MenuData::new("/flag", "功能开关", icondata::AiFlagOutlined))
+        .add_child_menu(MenuData::new(
+            "/approval",
+            "待审批",
+            icondata::AiAuditOutlined,
+        ))
         .add_child_menu(MenuData::new(
             "/dict",
             "字典管理",
             icondata::AiTableOutlined,
         ))
+        .add_child_menu(MenuData::new(
+            "/audit",
+            "审计日志",
+            icondata::AiFileSearchOutlined,
+        ))
         .to_owned()
 }
@@ -1,6 +1,7 @@
 mod app;
 mod common;
 mod ipc;
+mod model;
 mod page;
 mod service;
 
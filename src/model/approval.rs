@@ -0,0 +1,189 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use tower::tauri_web::prelude::*;
+
+use super::UpdateConfigReqExt;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChangeRequestStatus {
+    #[default]
+    Pending,
+    Approved,
+    Rejected,
+}
+impl ChangeRequestStatus {
+    pub fn all() -> Vec<ChangeRequestStatus> {
+        vec![
+            ChangeRequestStatus::Pending,
+            ChangeRequestStatus::Approved,
+            ChangeRequestStatus::Rejected,
+        ]
+    }
+}
+impl Labelable for ChangeRequestStatus {
+    fn label(&self) -> String {
+        match self {
+            ChangeRequestStatus::Pending => "待审批".to_string(),
+            ChangeRequestStatus::Approved => "已通过".to_string(),
+            ChangeRequestStatus::Rejected => "已驳回".to_string(),
+        }
+    }
+}
+impl Display for ChangeRequestStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangeRequestStatus::Pending => write!(f, "Pending"),
+            ChangeRequestStatus::Approved => write!(f, "Approved"),
+            ChangeRequestStatus::Rejected => write!(f, "Rejected"),
+        }
+    }
+}
+impl TryFrom<String> for ChangeRequestStatus {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "Pending" => Ok(ChangeRequestStatus::Pending),
+            "Approved" => Ok(ChangeRequestStatus::Approved),
+            "Rejected" => Ok(ChangeRequestStatus::Rejected),
+            _ => Err(format!("未知的审批状态：{}", value)),
+        }
+    }
+}
+
+/// 受保护配置的快照，密钥配置的`var_value`由服务端掩码
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigSnapshot {
+    pub app_version: Option<String>,
+    pub var_name: String,
+    pub var_value: String,
+    pub public: YesNo,
+    pub secret: YesNo,
+    pub protected: YesNo,
+    pub remark: Option<String>,
+    pub status: YesNo,
+}
+impl ConfigSnapshot {
+    /// 变更前后不同的字段：(字段名, 变更前, 变更后)
+    pub fn diff(&self, after: &ConfigSnapshot) -> Vec<(&'static str, String, String)> {
+        let mut diff = vec![];
+        let mut push = |field: &'static str, before: String, after: String| {
+            if before != after {
+                diff.push((field, before, after));
+            }
+        };
+        push(
+            "应用版本",
+            self.app_version.clone().unwrap_or_default(),
+            after.app_version.clone().unwrap_or_default(),
+        );
+        push("配置名称", self.var_name.clone(), after.var_name.clone());
+        push("配置值", self.var_value.clone(), after.var_value.clone());
+        push("是否公共", self.public.label(), after.public.label());
+        push("是否密钥", self.secret.label(), after.secret.label());
+        push(
+            "是否受保护",
+            self.protected.label(),
+            after.protected.label(),
+        );
+        push(
+            "备注",
+            self.remark.clone().unwrap_or_default(),
+            after.remark.clone().unwrap_or_default(),
+        );
+        push("是否有效", self.status.label(), after.status.label());
+        diff
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigChangeListItem {
+    pub id: i32,
+    pub config_id: i32,
+    pub app: App,
+    pub before: ConfigSnapshot,
+    pub after: ConfigSnapshot,
+    pub reason: Option<String>,
+    pub requester: String,
+    pub status: ChangeRequestStatus,
+    pub reviewer: Option<String>,
+    pub review_comment: Option<String>,
+    pub created: i64,
+    pub reviewed: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigChangeListReq {
+    pub status: Option<ChangeRequestStatus>,
+}
+
+/// 受保护配置的修改不直接生效，而是提交变更申请
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitConfigChangeReq {
+    #[serde(flatten)]
+    pub change: UpdateConfigReqExt,
+    pub reason: Option<String>,
+}
+
+/// 审批人不能是申请人，由服务端校验审批角色
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewConfigChangeReq {
+    pub id: i32,
+    pub approved: bool,
+    pub comment: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> ConfigSnapshot {
+        ConfigSnapshot {
+            app_version: None,
+            var_name: "timeout".to_string(),
+            var_value: "30".to_string(),
+            public: YesNo::No,
+            secret: YesNo::No,
+            protected: YesNo::Yes,
+            remark: None,
+            status: YesNo::Yes,
+        }
+    }
+
+    #[test]
+    fn diff_of_same_snapshot_is_empty() {
+        assert!(snapshot().diff(&snapshot()).is_empty());
+    }
+
+    #[test]
+    fn diff_lists_changed_fields_in_order() {
+        let before = snapshot();
+        let after = ConfigSnapshot {
+            var_value: "60".to_string(),
+            status: YesNo::No,
+            ..snapshot()
+        };
+        assert_eq!(
+            before.diff(&after),
+            vec![
+                ("配置值", "30".to_string(), "60".to_string()),
+                ("是否有效", YesNo::Yes.label(), YesNo::No.label()),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_treats_none_as_empty() {
+        let before = snapshot();
+        let after = ConfigSnapshot {
+            app_version: Some("".to_string()),
+            remark: Some("调大超时".to_string()),
+            ..snapshot()
+        };
+        assert_eq!(
+            before.diff(&after),
+            vec![("备注", "".to_string(), "调大超时".to_string())]
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use tower::tauri_web::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AuditAction {
+    Add,
+    Update,
+    Delete,
+    Restore,
+    Sort,
+    Save,
+    SetStatus,
+    ResetPassword,
+    Assign,
+    Schedule,
+    Cancel,
+    Clone,
+    Submit,
+    Review,
+    Apply,
+    Publish,
+    Rollback,
+}
+impl Labelable for AuditAction {
+    fn label(&self) -> String {
+        match self {
+            AuditAction::Add => "新增".to_string(),
+            AuditAction::Update => "修改".to_string(),
+            AuditAction::Delete => "删除".to_string(),
+            AuditAction::Restore => "恢复".to_string(),
+            AuditAction::Sort => "排序".to_string(),
+            AuditAction::Save => "保存".to_string(),
+            AuditAction::SetStatus => "启用/禁用".to_string(),
+            AuditAction::ResetPassword => "重置密码".to_string(),
+            AuditAction::Assign => "分配".to_string(),
+            AuditAction::Schedule => "定时变更".to_string(),
+            AuditAction::Cancel => "取消".to_string(),
+            AuditAction::Clone => "克隆".to_string(),
+            AuditAction::Submit => "提交审批".to_string(),
+            AuditAction::Review => "审批".to_string(),
+            AuditAction::Apply => "应用".to_string(),
+            AuditAction::Publish => "发布".to_string(),
+            AuditAction::Rollback => "回滚".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AuditResourceType {
+    User,
+    UserApp,
+    UserRole,
+    UserGroup,
+    UserVipLevel,
+    Role,
+    Group,
+    Config,
+    ConfigChange,
+    ConfigSchedule,
+    ConfigTemplate,
+    Flag,
+    Dict,
+    DictItem,
+    DictDependency,
+}
+impl AuditResourceType {
+    pub fn all() -> Vec<AuditResourceType> {
+        vec![
+            AuditResourceType::User,
+            AuditResourceType::UserApp,
+            AuditResourceType::UserRole,
+            AuditResourceType::UserGroup,
+            AuditResourceType::UserVipLevel,
+            AuditResourceType::Role,
+            AuditResourceType::Group,
+            AuditResourceType::Config,
+            AuditResourceType::ConfigChange,
+            AuditResourceType::ConfigSchedule,
+            AuditResourceType::ConfigTemplate,
+            AuditResourceType::Flag,
+            AuditResourceType::Dict,
+            AuditResourceType::DictItem,
+            AuditResourceType::DictDependency,
+        ]
+    }
+}
+impl Labelable for AuditResourceType {
+    fn label(&self) -> String {
+        match self {
+            AuditResourceType::User => "用户".to_string(),
+            AuditResourceType::UserApp => "用户应用".to_string(),
+            AuditResourceType::UserRole => "用户角色".to_string(),
+            AuditResourceType::UserGroup => "用户组".to_string(),
+            AuditResourceType::UserVipLevel => "会员等级".to_string(),
+            AuditResourceType::Role => "角色".to_string(),
+            AuditResourceType::Group => "分组".to_string(),
+            AuditResourceType::Config => "配置".to_string(),
+            AuditResourceType::ConfigChange => "配置变更申请".to_string(),
+            AuditResourceType::ConfigSchedule => "配置定时变更".to_string(),
+            AuditResourceType::ConfigTemplate => "配置模板".to_string(),
+            AuditResourceType::Flag => "功能开关".to_string(),
+            AuditResourceType::Dict => "字典".to_string(),
+            AuditResourceType::DictItem => "字典项".to_string(),
+            AuditResourceType::DictDependency => "字典依赖".to_string(),
+        }
+    }
+}
+impl Display for AuditResourceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditResourceType::User => write!(f, "User"),
+            AuditResourceType::UserApp => write!(f, "UserApp"),
+            AuditResourceType::UserRole => write!(f, "UserRole"),
+            AuditResourceType::UserGroup => write!(f, "UserGroup"),
+            AuditResourceType::UserVipLevel => write!(f, "UserVipLevel"),
+            AuditResourceType::Role => write!(f, "Role"),
+            AuditResourceType::Group => write!(f, "Group"),
+            AuditResourceType::Config => write!(f, "Config"),
+            AuditResourceType::ConfigChange => write!(f, "ConfigChange"),
+            AuditResourceType::ConfigSchedule => write!(f, "ConfigSchedule"),
+            AuditResourceType::ConfigTemplate => write!(f, "ConfigTemplate"),
+            AuditResourceType::Flag => write!(f, "Flag"),
+            AuditResourceType::Dict => write!(f, "Dict"),
+            AuditResourceType::DictItem => write!(f, "DictItem"),
+            AuditResourceType::DictDependency => write!(f, "DictDependency"),
+        }
+    }
+}
+impl TryFrom<String> for AuditResourceType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "User" => Ok(AuditResourceType::User),
+            "UserApp" => Ok(AuditResourceType::UserApp),
+            "UserRole" => Ok(AuditResourceType::UserRole),
+            "UserGroup" => Ok(AuditResourceType::UserGroup),
+            "UserVipLevel" => Ok(AuditResourceType::UserVipLevel),
+            "Role" => Ok(AuditResourceType::Role),
+            "Group" => Ok(AuditResourceType::Group),
+            "Config" => Ok(AuditResourceType::Config),
+            "ConfigChange" => Ok(AuditResourceType::ConfigChange),
+            "ConfigSchedule" => Ok(AuditResourceType::ConfigSchedule),
+            "ConfigTemplate" => Ok(AuditResourceType::ConfigTemplate),
+            "Flag" => Ok(AuditResourceType::Flag),
+            "Dict" => Ok(AuditResourceType::Dict),
+            "DictItem" => Ok(AuditResourceType::DictItem),
+            "DictDependency" => Ok(AuditResourceType::DictDependency),
+            _ => Err(format!("未知的资源类型：{}", value)),
+        }
+    }
+}
+
+/// 发起操作的客户端，由服务端按请求头记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditClient {
+    pub app: App,
+    pub user_agent: Option<String>,
+}
+
+/// 审计记录由服务端在变更的同一事务中写入，操作人、时间、IP、客户端均取自会话和请求，客户端只读。
+///
+/// 每种资源只用一个稳定的键作为`target_id`，新增时为服务端生成的id：
+/// 用户及其应用、角色、用户组、会员等级为用户id；字典、字典依赖为字典代码；其余为记录id。
+/// `parent_id`为所属对象：配置定时变更、配置变更申请为配置id，字典项为字典代码，其余为空。
+/// 密钥配置的值按目标配置的`secret`在服务端脱敏，变更前后都不含明文。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditListItem {
+    pub id: i64,
+    pub actor: String,
+    pub action: AuditAction,
+    pub resource_type: AuditResourceType,
+    pub target_id: String,
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+    pub client: AuditClient,
+    #[serde(default)]
+    pub ip: Option<String>,
+    pub created: i64,
+}
+
+/// 各条件为空表示不限，按时间倒序返回最近`limit`条
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AuditListReq {
+    pub actor: Option<String>,
+    pub resource_types: Vec<AuditResourceType>,
+    pub target_id: Option<String>,
+    /// 不为空时，同时返回`parent_id`为`target_id`的这些类型的记录，如字典下字典项的记录
+    pub child_types: Vec<AuditResourceType>,
+    pub from: Option<i64>,
+    pub until: Option<i64>,
+    pub limit: u32,
+}
+
+/// 变更前后不同的顶层字段，`(字段, 变更前, 变更后)`
+pub fn audit_changes(
+    before: Option<&serde_json::Value>,
+    after: Option<&serde_json::Value>,
+) -> Vec<(String, Option<serde_json::Value>, Option<serde_json::Value>)> {
+    let field = |value: Option<&serde_json::Value>, key: &str| {
+        value.and_then(|value| value.get(key)).cloned()
+    };
+    let mut keys: Vec<String> = vec![];
+    for value in [before, after].into_iter().flatten() {
+        if let Some(object) = value.as_object() {
+            for key in object.keys() {
+                if !keys.contains(key) {
+                    keys.push(key.clone());
+                }
+            }
+        }
+    }
+    // 只有变更前或变更后时列出全部字段；都有时只比较请求中的字段，查询结果中的创建时间等不算变更
+    keys.into_iter()
+        .filter_map(|key| {
+            let (old, new) = (field(before, &key), field(after, &key));
+            (before.is_none() || after.is_none() || (new.is_some() && old != new))
+                .then(|| (key, old, new))
+        })
+        .collect()
+}
@@ -0,0 +1,230 @@
+use std::ops::Deref;
+
+use serde::{Deserialize, Serialize};
+use tower::{
+    management_model::{AddConfigReq, ConfigListItem, ConfigListReq, UpdateConfigReq},
+    tauri_web::prelude::*,
+};
+
+/// 密钥配置在列表、表单中的掩码显示
+pub const SECRET_MASK: &str = "******";
+
+/// 无客户端读取超过该天数的配置标记为待清理
+pub const STALE_DAYS: i64 = 90;
+
+/// 应用版本规则：精确版本`1.3.2`，或带比较符的范围`>=1.4`
+pub const APP_VERSION_PATTERN: &str = "(>=|>|<=|<|=)?\\d+(\\.\\d+){0,2}";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigListReqExt {
+    #[serde(flatten)]
+    pub base: ConfigListReq,
+    pub app_version: Option<String>,
+}
+
+/// 列表、搜索接口不返回密钥配置的明文，`var_value`由服务端替换为[`SECRET_MASK`]，明文只能通过`/reveal`查看；
+/// `secret`、`protected`为必填，缺失时按接口错误处理，不默认为否
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigListItemExt {
+    #[serde(flatten)]
+    pub base: ConfigListItem,
+    pub secret: YesNo,
+    pub protected: YesNo,
+    #[serde(default)]
+    pub last_read: Option<i64>,
+    #[serde(default)]
+    pub dict_code: Option<String>,
+}
+impl Deref for ConfigListItemExt {
+    type Target = ConfigListItem;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+impl ConfigListItemExt {
+    pub fn is_secret(&self) -> bool {
+        self.secret == YesNo::Yes
+    }
+    pub fn is_protected(&self) -> bool {
+        self.protected == YesNo::Yes
+    }
+    /// 超过[`STALE_DAYS`]天无客户端读取，或从未被读取
+    pub fn is_stale(&self, now_secs: i64) -> bool {
+        self.last_read.map_or(true, |last_read| {
+            now_secs - last_read > STALE_DAYS * 24 * 3600
+        })
+    }
+    /// 服务端未脱敏时在客户端兜底，密钥明文不进入页面状态
+    pub fn mask_secret(mut self) -> Self {
+        if self.is_secret() {
+            self.base.var_value = SECRET_MASK.to_string();
+        }
+        self
+    }
+    pub fn masked_value(&self) -> String {
+        if self.is_secret() {
+            SECRET_MASK.to_string()
+        } else {
+            self.var_value.clone()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddConfigReqExt {
+    #[serde(flatten)]
+    pub base: AddConfigReq,
+    pub secret: YesNo,
+    pub protected: YesNo,
+    pub dict_code: Option<String>,
+}
+
+/// 密钥配置的`var_value`为空时，服务端保留原值
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateConfigReqExt {
+    #[serde(flatten)]
+    pub base: UpdateConfigReq,
+    pub secret: YesNo,
+    pub protected: YesNo,
+    pub dict_code: Option<String>,
+}
+
+/// 查看密钥配置明文，需要重新输入密码，服务端记录审计日志
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevealConfigReq {
+    pub id: i32,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RevealConfigResp {
+    pub var_value: String,
+}
+
+/// 导出配置，`include_secret`为真时需要重新输入密码
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportConfigReq {
+    #[serde(flatten)]
+    pub query: ConfigListReqExt,
+    pub include_secret: bool,
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigExportItem {
+    pub app_id: String,
+    pub app_version: Option<String>,
+    pub var_name: String,
+    pub var_value: Option<String>,
+    pub public: YesNo,
+    pub secret: YesNo,
+    pub remark: Option<String>,
+    pub status: YesNo,
+}
+
+/// 按应用及客户端版本解析生效配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolveConfigReq {
+    pub app_id: String,
+    pub app_version: String,
+}
+
+/// `app_version`为命中的版本规则，为空表示未被覆盖；密钥配置的`var_value`同列表接口一样已脱敏
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolvedConfigItem {
+    pub id: i32,
+    pub var_name: String,
+    pub var_value: String,
+    pub app_version: Option<String>,
+    pub secret: YesNo,
+}
+impl ResolvedConfigItem {
+    pub fn mask_secret(mut self) -> Self {
+        if self.secret == YesNo::Yes {
+            self.var_value = SECRET_MASK.to_string();
+        }
+        self
+    }
+}
+
+/// 定时变更，`var_value`、`status`为空表示不修改该项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledChangeListItem {
+    pub id: i32,
+    pub config_id: i32,
+    pub app: App,
+    pub var_name: String,
+    pub var_value: Option<String>,
+    pub status: Option<YesNo>,
+    pub secret: YesNo,
+    /// 配置限定的字典
+    #[serde(default)]
+    pub dict_code: Option<String>,
+    pub fire_at: i64,
+    pub creator: String,
+    pub created: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledChangeListReq {
+    pub app_id: String,
+}
+
+/// 受保护配置不能定时变更；配置限定字典时`var_value`必须为有效的字典项值，均由服务端校验
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveScheduledChangeReq {
+    pub id: Option<i32>,
+    pub config_id: i32,
+    pub var_value: Option<String>,
+    pub status: Option<YesNo>,
+    pub fire_at: i64,
+}
+
+/// 跨应用搜索，各条件为空表示不限
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigSearchReq {
+    pub var_name: Option<String>,
+    /// 服务端只按值匹配非密钥配置，避免按值逐字猜出密钥明文
+    pub var_value: Option<String>,
+    pub remark: Option<String>,
+    pub status: Option<YesNo>,
+}
+
+/// 批量修改状态或是否公共，为空表示不修改；包含受保护配置时服务端拒绝，需逐项提交变更申请
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchUpdateConfigReq {
+    pub ids: Vec<i32>,
+    pub status: Option<YesNo>,
+    pub public: Option<YesNo>,
+}
+
+/// 包含受保护配置时服务端拒绝
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchDeleteConfigReq {
+    pub ids: Vec<i32>,
+}
+
+/// 批量克隆到目标应用及版本，目标已存在的同名配置跳过；包含受保护配置时服务端拒绝
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloneConfigsReq {
+    pub ids: Vec<i32>,
+    pub app_id: String,
+    pub app_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CloneConfigsResp {
+    pub created: Vec<String>,
+    pub skipped: Vec<String>,
+}
+
+/// 读取配置的客户端，按应用、版本、环境汇总
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigConsumerItem {
+    pub app: App,
+    pub app_version: Option<String>,
+    pub env: Option<String>,
+    pub last_read: i64,
+    pub read_count: u64,
+}
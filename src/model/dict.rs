@@ -0,0 +1,608 @@
+use std::{collections::BTreeMap, ops::Deref};
+
+use serde::{Deserialize, Serialize};
+use tower::{
+    management_model::{
+        AddDictItemReq, AddDictReq, DictItemListItem, DictListItem, UpdateDictItemReq,
+        UpdateDictReq,
+    },
+    tauri_web::prelude::*,
+};
+
+use super::{ConfigValueType, SECRET_MASK};
+
+/// 多语言名称，键为语言代码；中文名称即`dict_name`、`item_name`
+pub type LocaleLabels = BTreeMap<String, String>;
+
+/// 需要翻译的语言，`(语言代码, 名称)`
+pub const LOCALES: [(&str, &str); 2] = [("en", "English"), ("ja", "日本語")];
+
+/// 缺少翻译的语言代码
+pub fn missing_locales(labels: &LocaleLabels) -> Vec<&'static str> {
+    LOCALES
+        .iter()
+        .filter(|(locale, _)| {
+            labels
+                .get(*locale)
+                .map_or(true, |label| label.trim().is_empty())
+        })
+        .map(|(locale, _)| *locale)
+        .collect()
+}
+
+/// 删除字典为软删除；有下级字典或字典项时，`cascade`为真才一并删除
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteDictReq {
+    pub dict_code: String,
+    pub cascade: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoreDictReq {
+    pub dict_code: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoreDictItemReq {
+    pub id: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DictListItemExt {
+    #[serde(flatten)]
+    pub base: DictListItem,
+    #[serde(default)]
+    pub sort: i32,
+    #[serde(default)]
+    pub labels: LocaleLabels,
+    #[serde(default)]
+    pub ext_schema: Vec<DictExtField>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DictItemListItemExt {
+    #[serde(flatten)]
+    pub base: DictItemListItem,
+    #[serde(default)]
+    pub sort: i32,
+    #[serde(default)]
+    pub labels: LocaleLabels,
+    /// 上级字典项，用于省、市、区等级联选择
+    #[serde(default)]
+    pub parent_id: Option<i32>,
+    /// 徽标颜色，如`#1677ff`
+    #[serde(default)]
+    pub color: Option<String>,
+    /// 徽标图标，`icondata`中的名称，如`AiStarOutlined`
+    #[serde(default)]
+    pub icon: Option<String>,
+    #[serde(default)]
+    pub ext: Option<serde_json::Value>,
+    /// 生效时间，未到时客户端查询不返回该字典项
+    #[serde(default)]
+    pub valid_from: Option<i64>,
+    /// 失效时间，之后客户端查询不返回该字典项
+    #[serde(default)]
+    pub valid_until: Option<i64>,
+}
+impl Deref for DictItemListItemExt {
+    type Target = DictItemListItem;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+impl DictItemListItemExt {
+    pub fn validity(&self, now_secs: i64) -> DictItemValidity {
+        if self
+            .valid_from
+            .is_some_and(|valid_from| now_secs < valid_from)
+        {
+            DictItemValidity::Scheduled
+        } else if self
+            .valid_until
+            .is_some_and(|valid_until| now_secs >= valid_until)
+        {
+            DictItemValidity::Expired
+        } else {
+            DictItemValidity::Active
+        }
+    }
+}
+
+/// 字典项在有效期内的状态，与`status`无关
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DictItemValidity {
+    Scheduled,
+    Active,
+    Expired,
+}
+impl Labelable for DictItemValidity {
+    fn label(&self) -> String {
+        match self {
+            DictItemValidity::Scheduled => "未生效".to_string(),
+            DictItemValidity::Active => "生效中".to_string(),
+            DictItemValidity::Expired => "已过期".to_string(),
+        }
+    }
+}
+
+/// 生效时间须早于失效时间
+pub fn check_validity_window(
+    valid_from: Option<i64>,
+    valid_until: Option<i64>,
+) -> Result<(), String> {
+    match (valid_from, valid_until) {
+        (Some(valid_from), Some(valid_until)) if valid_from >= valid_until => {
+            Err("生效时间须早于失效时间".to_string())
+        }
+        _ => Ok(()),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddDictReqExt {
+    #[serde(flatten)]
+    pub base: AddDictReq,
+    pub labels: LocaleLabels,
+    pub ext_schema: Vec<DictExtField>,
+}
+
+/// `labels`、`ext_schema`为空时，服务端保留原值
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateDictReqExt {
+    #[serde(flatten)]
+    pub base: UpdateDictReq,
+    pub labels: Option<LocaleLabels>,
+    pub ext_schema: Option<Vec<DictExtField>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddDictItemReqExt {
+    #[serde(flatten)]
+    pub base: AddDictItemReq,
+    pub labels: LocaleLabels,
+    pub parent_id: Option<i32>,
+    pub color: Option<String>,
+    pub icon: Option<String>,
+    pub ext: Option<serde_json::Value>,
+    pub valid_from: Option<i64>,
+    pub valid_until: Option<i64>,
+}
+
+/// `labels`为空时，服务端保留原值；其他可选字段为空时清除
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateDictItemReqExt {
+    #[serde(flatten)]
+    pub base: UpdateDictItemReq,
+    pub labels: Option<LocaleLabels>,
+    pub color: Option<String>,
+    pub icon: Option<String>,
+    pub ext: Option<serde_json::Value>,
+    pub valid_from: Option<i64>,
+    pub valid_until: Option<i64>,
+}
+
+/// 字典项扩展字段的定义，字典项的`ext`按字典的定义校验
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DictExtField {
+    pub name: String,
+    pub value_type: ConfigValueType,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+/// 解析并校验字典项的`ext`，为空时返回`None`；字典未定义扩展字段时只要求是JSON对象
+pub fn parse_dict_ext(
+    schema: &[DictExtField],
+    ext: &str,
+) -> Result<Option<serde_json::Value>, String> {
+    if ext.trim().is_empty() {
+        return match schema.iter().find(|field| field.required) {
+            Some(field) => Err(format!("缺少扩展字段{}", field.name)),
+            None => Ok(None),
+        };
+    }
+    let value = serde_json::from_str::<serde_json::Value>(ext)
+        .map_err(|err| format!("扩展字段JSON格式错误：{}", err))?;
+    let Some(object) = value.as_object() else {
+        return Err("扩展字段必须是JSON对象".to_string());
+    };
+    if schema.is_empty() {
+        return Ok(Some(value));
+    }
+    if let Some(name) = object
+        .keys()
+        .find(|name| !schema.iter().any(|field| &field.name == *name))
+    {
+        return Err(format!("未定义的扩展字段{}", name));
+    }
+    for field in schema {
+        let matched = match object.get(&field.name) {
+            None | Some(serde_json::Value::Null) if field.required => {
+                return Err(format!("缺少扩展字段{}", field.name))
+            }
+            None | Some(serde_json::Value::Null) => true,
+            Some(value) => match field.value_type {
+                ConfigValueType::String => value.is_string(),
+                ConfigValueType::Number => value.is_number(),
+                ConfigValueType::Bool => value.is_boolean(),
+                ConfigValueType::Json => true,
+            },
+        };
+        if !matched {
+            return Err(format!(
+                "扩展字段{}应为{}",
+                field.name,
+                field.value_type.label()
+            ));
+        }
+    }
+    Ok(Some(value))
+}
+
+/// 树形表格的一行，`ancestors`为从顶级到直接上级的id
+#[derive(Debug, Clone)]
+pub struct DictItemRow {
+    pub depth: usize,
+    pub ancestors: Vec<i32>,
+    pub has_children: bool,
+    pub item: DictItemListItemExt,
+}
+
+/// 按树的顺序展开字典项，同级保持原顺序；上级不存在的字典项作为顶级
+pub fn dict_item_rows(items: &[DictItemListItemExt]) -> Vec<DictItemRow> {
+    fn walk(
+        items: &[DictItemListItemExt],
+        parent_id: Option<i32>,
+        ancestors: &mut Vec<i32>,
+        rows: &mut Vec<DictItemRow>,
+    ) {
+        for item in items.iter().filter(|item| item.parent_id == parent_id) {
+            if ancestors.contains(&item.id) {
+                continue;
+            }
+            rows.push(DictItemRow {
+                depth: ancestors.len(),
+                ancestors: ancestors.clone(),
+                has_children: items.iter().any(|it| it.parent_id == Some(item.id)),
+                item: item.clone(),
+            });
+            ancestors.push(item.id);
+            walk(items, Some(item.id), ancestors, rows);
+            ancestors.pop();
+        }
+    }
+    let ids: Vec<i32> = items.iter().map(|item| item.id).collect();
+    let mut rows = vec![];
+    for item in items.iter().filter(|item| {
+        item.parent_id
+            .is_some_and(|parent_id| !ids.contains(&parent_id))
+    }) {
+        rows.push(DictItemRow {
+            depth: 0,
+            ancestors: vec![],
+            has_children: items.iter().any(|it| it.parent_id == Some(item.id)),
+            item: item.clone(),
+        });
+        walk(items, Some(item.id), &mut vec![item.id], &mut rows);
+    }
+    let mut top = vec![];
+    walk(items, None, &mut vec![], &mut top);
+    top.extend(rows);
+    top
+}
+
+/// 批量新增时粘贴的一行，`problem`不为空时不会新增
+#[derive(Debug, Clone, PartialEq)]
+pub struct BulkItemLine {
+    pub line: usize,
+    pub item_name: String,
+    pub item_value: String,
+    pub remark: Option<String>,
+    pub problem: Option<String>,
+}
+
+/// 每行`名称,值[,备注]`，含制表符时按制表符分隔（从Excel复制）；跳过空行
+pub fn parse_bulk_items(text: &str, existing: &[DictItemListItemExt]) -> Vec<BulkItemLine> {
+    let mut lines: Vec<BulkItemLine> = vec![];
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let separator = if line.contains('\t') { '\t' } else { ',' };
+        let cols: Vec<&str> = line.splitn(3, separator).map(|col| col.trim()).collect();
+        let col = |index: usize| cols.get(index).copied().unwrap_or_default().to_string();
+        let (item_name, item_value) = (col(0), col(1));
+        let problem = if item_name.is_empty() || item_value.is_empty() {
+            Some("名称和值不能为空".to_string())
+        } else if item_name.chars().count() > 50 || item_value.chars().count() > 50 {
+            Some("名称或值超过50个字符".to_string())
+        } else if existing.iter().any(|item| item.item_value == item_value) {
+            Some("值已存在".to_string())
+        } else if let Some(first) = lines.iter().find(|it| it.item_value == item_value) {
+            Some(format!("与第{}行的值重复", first.line))
+        } else {
+            None
+        };
+        lines.push(BulkItemLine {
+            line: index + 1,
+            item_name,
+            item_value,
+            remark: str_trim_to_option(col(2)),
+            problem,
+        });
+    }
+    lines
+}
+
+/// 按`dict_codes`的顺序重排同一上级下的字典
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SortDictsReq {
+    pub parent_code: Option<String>,
+    pub dict_codes: Vec<String>,
+}
+
+/// 按`ids`的顺序重排字典项，客户端下拉框使用该顺序
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SortDictItemsReq {
+    pub dict_code: String,
+    pub ids: Vec<i32>,
+}
+
+/// `item_value`为空时查询整个字典的引用
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DictUsageReq {
+    pub dict_code: String,
+    pub item_value: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DictConfigUsage {
+    pub id: i32,
+    pub app: App,
+    pub var_name: String,
+    pub var_value: String,
+    pub secret: YesNo,
+}
+impl DictConfigUsage {
+    /// 同[`super::ConfigListItemExt::mask_secret`]，密钥明文不进入页面状态
+    pub fn mask_secret(mut self) -> Self {
+        if self.secret == YesNo::Yes {
+            self.var_value = SECRET_MASK.to_string();
+        }
+        self
+    }
+}
+
+/// 应用登记的字典依赖
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DictAppDependency {
+    pub id: i32,
+    pub app: App,
+    pub dict_code: String,
+    pub created: i64,
+}
+
+/// 字典或字典项的引用：取值限定为该字典的配置、引用该字典的其他字典（如下级字典）、登记依赖的应用
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DictUsage {
+    pub configs: Vec<DictConfigUsage>,
+    pub dicts: Vec<String>,
+    pub apps: Vec<DictAppDependency>,
+}
+impl DictUsage {
+    pub fn is_empty(&self) -> bool {
+        self.configs.is_empty() && self.dicts.is_empty() && self.apps.is_empty()
+    }
+    pub fn mask_secret(mut self) -> Self {
+        self.configs = self
+            .configs
+            .into_iter()
+            .map(DictConfigUsage::mask_secret)
+            .collect();
+        self
+    }
+    pub fn summary(&self) -> String {
+        format!(
+            "被 {} 个配置、{} 个字典、{} 个应用引用",
+            self.configs.len(),
+            self.dicts.len(),
+            self.apps.len()
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddDictDependencyReq {
+    pub dict_code: String,
+    pub app: App,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: i32, item_value: &str, parent_id: Option<i32>) -> DictItemListItemExt {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "dict_code": "region",
+            "item_name": item_value,
+            "item_value": item_value,
+            "public": YesNo::Yes,
+            "status": YesNo::Yes,
+            "remark": null,
+            "created": 0,
+            "updated": null,
+            "parent_id": parent_id,
+        }))
+        .unwrap()
+    }
+
+    fn row_values(rows: &[DictItemRow]) -> Vec<(usize, &str, bool)> {
+        rows.iter()
+            .map(|row| (row.depth, row.item.item_value.as_str(), row.has_children))
+            .collect()
+    }
+
+    #[test]
+    fn item_rows_follow_tree_order() {
+        let items = vec![
+            item(1, "zj", None),
+            item(2, "hz", Some(1)),
+            item(3, "js", None),
+            item(4, "xh", Some(2)),
+            item(5, "nj", Some(3)),
+        ];
+        let rows = dict_item_rows(&items);
+        assert_eq!(
+            row_values(&rows),
+            vec![
+                (0, "zj", true),
+                (1, "hz", true),
+                (2, "xh", false),
+                (0, "js", true),
+                (1, "nj", false),
+            ]
+        );
+        assert_eq!(rows[2].ancestors, vec![1, 2]);
+    }
+
+    #[test]
+    fn item_rows_put_orphans_after_top_level() {
+        let items = vec![
+            item(2, "orphan", Some(9)),
+            item(3, "child", Some(2)),
+            item(1, "zj", None),
+        ];
+        let rows = dict_item_rows(&items);
+        assert_eq!(
+            row_values(&rows),
+            vec![(0, "zj", false), (0, "orphan", true), (1, "child", false)]
+        );
+        assert_eq!(rows[2].ancestors, vec![2]);
+    }
+
+    fn field(name: &str, value_type: ConfigValueType, required: bool) -> DictExtField {
+        DictExtField {
+            name: name.to_string(),
+            value_type,
+            required,
+            remark: None,
+        }
+    }
+
+    #[test]
+    fn ext_without_schema_only_requires_object() {
+        assert_eq!(parse_dict_ext(&[], " "), Ok(None));
+        assert_eq!(
+            parse_dict_ext(&[], r#"{"any": 1}"#),
+            Ok(Some(serde_json::json!({"any": 1})))
+        );
+        assert!(parse_dict_ext(&[], "[1]").is_err());
+        assert!(parse_dict_ext(&[], "{").is_err());
+    }
+
+    #[test]
+    fn ext_checked_against_schema() {
+        let schema = vec![
+            field("code", ConfigValueType::String, true),
+            field("rank", ConfigValueType::Number, false),
+        ];
+        assert!(parse_dict_ext(&schema, r#"{"code": "zj", "rank": 1}"#).is_ok());
+        assert!(parse_dict_ext(&schema, r#"{"code": "zj", "rank": null}"#).is_ok());
+        assert_eq!(
+            parse_dict_ext(&schema, ""),
+            Err("缺少扩展字段code".to_string())
+        );
+        assert_eq!(
+            parse_dict_ext(&schema, r#"{"rank": 1}"#),
+            Err("缺少扩展字段code".to_string())
+        );
+        assert_eq!(
+            parse_dict_ext(&schema, r#"{"code": "zj", "extra": 1}"#),
+            Err("未定义的扩展字段extra".to_string())
+        );
+        assert_eq!(
+            parse_dict_ext(&schema, r#"{"code": "zj", "rank": "1"}"#),
+            Err(format!(
+                "扩展字段rank应为{}",
+                ConfigValueType::Number.label()
+            ))
+        );
+    }
+
+    #[test]
+    fn bulk_items_split_by_comma_or_tab() {
+        let lines = parse_bulk_items("浙江,zj,东部\n\n江苏\tjs\t含,逗号\n", &[]);
+        assert_eq!(
+            lines,
+            vec![
+                BulkItemLine {
+                    line: 1,
+                    item_name: "浙江".to_string(),
+                    item_value: "zj".to_string(),
+                    remark: Some("东部".to_string()),
+                    problem: None,
+                },
+                BulkItemLine {
+                    line: 3,
+                    item_name: "江苏".to_string(),
+                    item_value: "js".to_string(),
+                    remark: Some("含,逗号".to_string()),
+                    problem: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn bulk_items_report_problems() {
+        let existing = vec![item(1, "zj", None)];
+        let text = format!(
+            "浙江,zj\n江苏\n,sh\n江苏,js\n南京,js\n{},long",
+            "长".repeat(51)
+        );
+        let problems: Vec<_> = parse_bulk_items(&text, &existing)
+            .into_iter()
+            .map(|line| (line.line, line.problem))
+            .collect();
+        assert_eq!(
+            problems,
+            vec![
+                (1, Some("值已存在".to_string())),
+                (2, Some("名称和值不能为空".to_string())),
+                (3, Some("名称和值不能为空".to_string())),
+                (4, None),
+                (5, Some("与第4行的值重复".to_string())),
+                (6, Some("名称或值超过50个字符".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn validity_window_order() {
+        assert_eq!(check_validity_window(None, None), Ok(()));
+        assert_eq!(check_validity_window(Some(100), None), Ok(()));
+        assert_eq!(check_validity_window(None, Some(100)), Ok(()));
+        assert_eq!(check_validity_window(Some(100), Some(200)), Ok(()));
+        assert!(check_validity_window(Some(200), Some(200)).is_err());
+        assert!(check_validity_window(Some(300), Some(200)).is_err());
+    }
+
+    #[test]
+    fn validity_by_time() {
+        let scheduled = DictItemListItemExt {
+            valid_from: Some(100),
+            valid_until: Some(200),
+            ..item(1, "zj", None)
+        };
+        assert_eq!(scheduled.validity(99), DictItemValidity::Scheduled);
+        assert_eq!(scheduled.validity(100), DictItemValidity::Active);
+        assert_eq!(scheduled.validity(199), DictItemValidity::Active);
+        assert_eq!(scheduled.validity(200), DictItemValidity::Expired);
+        assert_eq!(item(2, "js", None).validity(0), DictItemValidity::Active);
+    }
+}
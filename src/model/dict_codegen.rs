@@ -0,0 +1,191 @@
+//! 由字典项生成Rust枚举，生成的代码与`tower::common::dict::VipLevel`等手写枚举一致：
+//! `all()`、`Labelable::label()`、`Display`（输出字典项的值）、`TryFrom<&str>`。
+//!
+//! 本文件只依赖标准库，`build.rs`中可以直接引入：
+//!
+//! ```ignore
+//! #[path = "../tower-management/src/model/dict_codegen.rs"]
+//! mod dict_codegen;
+//!
+//! let code = dict_codegen::dict_to_rust_enum("VipLevel", &items)?;
+//! std::fs::write(out_dir.join("vip_level.rs"), code)?;
+//! ```
+//!
+//! 引入生成代码的模块中需要有`Labelable`、`serde`。
+
+/// 生成枚举的一个变体，`value`为字典项的值，`label`为字典项的名称
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DictCodegenItem {
+    pub value: String,
+    pub label: String,
+}
+
+/// 首字母大写后仍是关键字的名称，不能作为变体名
+const RESERVED_NAMES: [&str; 1] = ["Self"];
+
+/// 字典项的值转为变体名：按非字母数字分词后首字母大写，数字开头或与关键字相同时加前缀`V`
+pub fn to_variant_name(value: &str) -> String {
+    let name: String = value
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) || RESERVED_NAMES.contains(&name.as_str()) {
+        format!("V{}", name)
+    } else {
+        name
+    }
+}
+
+pub fn dict_to_rust_enum(enum_name: &str, items: &[DictCodegenItem]) -> Result<String, String> {
+    if to_variant_name(enum_name) != enum_name {
+        return Err(format!("枚举名{}不是有效的类型名", enum_name));
+    }
+    if items.is_empty() {
+        return Err("字典没有字典项".to_string());
+    }
+    let mut variants: Vec<(String, &DictCodegenItem)> = vec![];
+    for item in items {
+        let variant = to_variant_name(&item.value);
+        if variant.is_empty() {
+            return Err(format!("字典项值{}无法转为变体名", item.value));
+        }
+        if let Some((_, existing)) = variants.iter().find(|(it, _)| *it == variant) {
+            return Err(format!(
+                "字典项值{}与{}转为相同的变体名{}",
+                item.value, existing.value, variant
+            ));
+        }
+        variants.push((variant, item));
+    }
+
+    let mut code = String::new();
+    code.push_str("// 由字典生成，请勿手动修改\n");
+    code.push_str(
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]\n",
+    );
+    code.push_str(&format!("pub enum {} {{\n", enum_name));
+    for (variant, item) in variants.iter() {
+        if *variant != item.value {
+            code.push_str(&format!("    #[serde(rename = {:?})]\n", item.value));
+        }
+        code.push_str(&format!("    {},\n", variant));
+    }
+    code.push_str("}\n");
+
+    code.push_str(&format!("impl {} {{\n", enum_name));
+    code.push_str(&format!("    pub fn all() -> Vec<{}> {{\n", enum_name));
+    code.push_str("        vec![\n");
+    for (variant, _) in variants.iter() {
+        code.push_str(&format!("            {}::{},\n", enum_name, variant));
+    }
+    code.push_str("        ]\n    }\n}\n");
+
+    code.push_str(&format!("impl Labelable for {} {{\n", enum_name));
+    code.push_str("    fn label(&self) -> String {\n        match self {\n");
+    for (variant, item) in variants.iter() {
+        code.push_str(&format!(
+            "            {}::{} => {:?}.to_string(),\n",
+            enum_name, variant, item.label
+        ));
+    }
+    code.push_str("        }\n    }\n}\n");
+
+    code.push_str(&format!("impl std::fmt::Display for {} {{\n", enum_name));
+    code.push_str(
+        "    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {\n        match self {\n",
+    );
+    for (variant, item) in variants.iter() {
+        code.push_str(&format!(
+            "            {}::{} => write!(f, \"{{}}\", {:?}),\n",
+            enum_name, variant, item.value
+        ));
+    }
+    code.push_str("        }\n    }\n}\n");
+
+    code.push_str(&format!("impl TryFrom<&str> for {} {{\n", enum_name));
+    code.push_str("    type Error = String;\n\n");
+    code.push_str(
+        "    fn try_from(value: &str) -> Result<Self, Self::Error> {\n        match value {\n",
+    );
+    for (variant, item) in variants.iter() {
+        code.push_str(&format!(
+            "            {:?} => Ok({}::{}),\n",
+            item.value, enum_name, variant
+        ));
+    }
+    code.push_str(&format!(
+        "            _ => Err(format!(\"未知的{}：{{}}\", value)),\n",
+        enum_name
+    ));
+    code.push_str("        }\n    }\n}\n");
+    Ok(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(value: &str, label: &str) -> DictCodegenItem {
+        DictCodegenItem {
+            value: value.to_string(),
+            label: label.to_string(),
+        }
+    }
+
+    #[test]
+    fn variant_names() {
+        assert_eq!(to_variant_name("male"), "Male");
+        assert_eq!(to_variant_name("Vip0"), "Vip0");
+        assert_eq!(to_variant_name("not_set"), "NotSet");
+        assert_eq!(to_variant_name("a-b c"), "ABC");
+        assert_eq!(to_variant_name("1st"), "V1st");
+        assert_eq!(to_variant_name("self"), "VSelf");
+        assert_eq!(to_variant_name("Self"), "VSelf");
+        assert_eq!(to_variant_name("--"), "");
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert!(dict_to_rust_enum("Gender", &[]).is_err());
+        assert!(dict_to_rust_enum("gender", &[item("male", "男")]).is_err());
+        assert!(dict_to_rust_enum("Self", &[item("male", "男")]).is_err());
+        assert!(dict_to_rust_enum("Gender", &[item("--", "无")]).is_err());
+    }
+
+    #[test]
+    fn rejects_collisions() {
+        let err = dict_to_rust_enum(
+            "Gender",
+            &[item("not_set", "未设置"), item("not-set", "未设置")],
+        )
+        .unwrap_err();
+        assert!(err.contains("NotSet"));
+    }
+
+    #[test]
+    fn serde_rename_only_when_name_differs() {
+        let code = dict_to_rust_enum(
+            "Level",
+            &[
+                item("Vip0", "普通"),
+                item("gold", "黄金"),
+                item("self", "本人"),
+            ],
+        )
+        .unwrap();
+        assert!(code.contains("pub enum Level {\n    Vip0,\n"));
+        assert!(code.contains("    #[serde(rename = \"gold\")]\n    Gold,\n"));
+        assert!(code.contains("    #[serde(rename = \"self\")]\n    VSelf,\n"));
+        assert!(!code.contains("rename = \"Vip0\""));
+        assert!(code.contains("Level::Gold => \"黄金\".to_string(),"));
+        assert!(code.contains("Level::Gold => write!(f, \"{}\", \"gold\"),"));
+        assert!(code.contains("\"self\" => Ok(Level::VSelf),"));
+    }
+}
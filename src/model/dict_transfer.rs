@@ -0,0 +1,768 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use serde::{Deserialize, Serialize};
+use tower::tauri_web::prelude::*;
+
+use super::{default_yes, DictItemListItemExt, LocaleLabels};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DictTransferFormat {
+    #[default]
+    Json,
+    Csv,
+}
+impl DictTransferFormat {
+    pub fn all() -> Vec<DictTransferFormat> {
+        vec![DictTransferFormat::Json, DictTransferFormat::Csv]
+    }
+    pub fn file_name(&self, dict_code: &str) -> String {
+        match self {
+            DictTransferFormat::Json => format!("{}.json", dict_code),
+            DictTransferFormat::Csv => format!("{}.csv", dict_code),
+        }
+    }
+    pub fn mime(&self) -> &'static str {
+        match self {
+            DictTransferFormat::Json => "application/json",
+            DictTransferFormat::Csv => "text/csv",
+        }
+    }
+}
+impl Labelable for DictTransferFormat {
+    fn label(&self) -> String {
+        match self {
+            DictTransferFormat::Json => "JSON".to_string(),
+            DictTransferFormat::Csv => "CSV".to_string(),
+        }
+    }
+}
+impl Display for DictTransferFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DictTransferFormat::Json => write!(f, "Json"),
+            DictTransferFormat::Csv => write!(f, "Csv"),
+        }
+    }
+}
+impl TryFrom<String> for DictTransferFormat {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "Json" => Ok(DictTransferFormat::Json),
+            "Csv" => Ok(DictTransferFormat::Csv),
+            _ => Err(format!("未知的文件格式：{}", value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DictTransferItem {
+    /// 当前字典项的id，仅用于导入时修改已有字典项
+    #[serde(skip)]
+    pub id: Option<i32>,
+    pub item_value: String,
+    pub item_name: String,
+    #[serde(default = "default_yes")]
+    pub public: YesNo,
+    #[serde(default = "default_yes")]
+    pub status: YesNo,
+    #[serde(default)]
+    pub remark: Option<String>,
+    /// 文件中没有`labels`时为空，修改时保留当前的多语言名称
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<LocaleLabels>,
+    /// 上级字典项的值；只在新增字典项时生效，修改不会调整上级
+    #[serde(default)]
+    pub parent_value: Option<String>,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub icon: Option<String>,
+    #[serde(default)]
+    pub ext: Option<serde_json::Value>,
+    #[serde(default)]
+    pub valid_from: Option<i64>,
+    #[serde(default)]
+    pub valid_until: Option<i64>,
+}
+impl DictTransferItem {
+    /// 上级字典项的id转为值
+    pub fn from_items(items: Vec<DictItemListItemExt>) -> Vec<DictTransferItem> {
+        let values: HashMap<i32, String> = items
+            .iter()
+            .map(|item| (item.id, item.item_value.clone()))
+            .collect();
+        items
+            .into_iter()
+            .map(|item| DictTransferItem {
+                id: Some(item.id),
+                item_value: item.base.item_value,
+                item_name: item.base.item_name,
+                public: item.base.public,
+                status: item.base.status,
+                remark: item.base.remark,
+                parent_value: item
+                    .parent_id
+                    .and_then(|parent_id| values.get(&parent_id).cloned()),
+                labels: Some(item.labels),
+                color: item.color,
+                icon: item.icon,
+                ext: item.ext,
+                valid_from: item.valid_from,
+                valid_until: item.valid_until,
+            })
+            .collect()
+    }
+    pub(crate) fn same_content(&self, other: &DictTransferItem) -> bool {
+        self.item_name == other.item_name
+            && (other.labels.is_none() || self.labels == other.labels)
+            && self.public == other.public
+            && self.status == other.status
+            && self.remark == other.remark
+            && self.color == other.color
+            && self.icon == other.icon
+            && self.ext == other.ext
+            && self.valid_from == other.valid_from
+            && self.valid_until == other.valid_until
+    }
+}
+
+/// 导入导出的字典，按树的顺序平铺，上级在前
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DictTransferDict {
+    pub dict_code: String,
+    pub dict_name: String,
+    #[serde(default)]
+    pub parent_code: Option<String>,
+    #[serde(default = "default_yes")]
+    pub public: YesNo,
+    #[serde(default = "default_yes")]
+    pub status: YesNo,
+    #[serde(default)]
+    pub remark: Option<String>,
+    /// 同[`DictTransferItem::labels`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<LocaleLabels>,
+    #[serde(default)]
+    pub items: Vec<DictTransferItem>,
+}
+impl DictTransferDict {
+    fn same_content(&self, other: &DictTransferDict) -> bool {
+        self.dict_name == other.dict_name
+            && (other.labels.is_none() || self.labels == other.labels)
+            && self.parent_code == other.parent_code
+            && self.public == other.public
+            && self.status == other.status
+            && self.remark == other.remark
+    }
+}
+
+const CSV_HEADER: [&str; 8] = [
+    "字典代码",
+    "字典名称",
+    "上级代码",
+    "字典备注",
+    "字典项值",
+    "字典项名称",
+    "字典项备注",
+    "上级字典项值",
+];
+
+/// CSV每行一个字典项，没有字典项的字典占一行且字典项列为空；
+/// CSV不含是否公共、是否有效、多语言名称，导入时新增默认为是，修改时保留原值
+pub fn dicts_to_csv(dicts: &[DictTransferDict]) -> String {
+    let mut lines = vec![CSV_HEADER.join(",")];
+    for dict in dicts {
+        let dict_cols = [
+            dict.dict_code.as_str(),
+            dict.dict_name.as_str(),
+            dict.parent_code.as_deref().unwrap_or_default(),
+            dict.remark.as_deref().unwrap_or_default(),
+        ];
+        if dict.items.is_empty() {
+            lines.push(csv_line(&[&dict_cols[..], &["", "", "", ""]].concat()));
+        }
+        for item in dict.items.iter() {
+            let item_cols = [
+                item.item_value.as_str(),
+                item.item_name.as_str(),
+                item.remark.as_deref().unwrap_or_default(),
+                item.parent_value.as_deref().unwrap_or_default(),
+            ];
+            lines.push(csv_line(&[&dict_cols[..], &item_cols[..]].concat()));
+        }
+    }
+    lines.join("\n")
+}
+
+pub fn dicts_from_csv(text: &str) -> Result<Vec<DictTransferDict>, String> {
+    let mut dicts: Vec<DictTransferDict> = vec![];
+    for (index, cols) in parse_csv(text)?.into_iter().enumerate().skip(1) {
+        if cols.iter().all(|col| col.trim().is_empty()) {
+            continue;
+        }
+        if cols.len() != CSV_HEADER.len() {
+            return Err(format!(
+                "第{}行应有{}列，实际为{}列",
+                index + 1,
+                CSV_HEADER.len(),
+                cols.len()
+            ));
+        }
+        let col = |i: usize| cols[i].trim().to_string();
+        let dict_code = col(0);
+        if dict_code.is_empty() {
+            return Err(format!("第{}行缺少字典代码", index + 1));
+        }
+        let position = match dicts.iter().position(|dict| dict.dict_code == dict_code) {
+            Some(position) => position,
+            None => {
+                dicts.push(DictTransferDict {
+                    dict_code,
+                    dict_name: col(1),
+                    parent_code: str_trim_to_option(col(2)),
+                    public: YesNo::Yes,
+                    status: YesNo::Yes,
+                    remark: str_trim_to_option(col(3)),
+                    labels: None,
+                    items: vec![],
+                });
+                dicts.len() - 1
+            }
+        };
+        let dict = &mut dicts[position];
+        if !col(4).is_empty() {
+            dict.items.push(DictTransferItem {
+                id: None,
+                item_value: col(4),
+                item_name: col(5),
+                public: YesNo::Yes,
+                status: YesNo::Yes,
+                remark: str_trim_to_option(col(6)),
+                labels: None,
+                parent_value: str_trim_to_option(col(7)),
+                color: None,
+                icon: None,
+                ext: None,
+                valid_from: None,
+                valid_until: None,
+            });
+        }
+    }
+    Ok(dicts)
+}
+
+/// CSV不含是否公共、是否有效、多语言名称、徽标、扩展字段和有效期，已存在的字典、字典项沿用当前值
+pub fn keep_current_flags(imported: &mut [DictTransferDict], current: &[DictTransferDict]) {
+    for dict in imported.iter_mut() {
+        let Some(existing) = current.iter().find(|it| it.dict_code == dict.dict_code) else {
+            continue;
+        };
+        dict.public = existing.public;
+        dict.status = existing.status;
+        dict.labels = existing.labels.clone();
+        for item in dict.items.iter_mut() {
+            if let Some(existing) = existing
+                .items
+                .iter()
+                .find(|it| it.item_value == item.item_value)
+            {
+                item.public = existing.public;
+                item.status = existing.status;
+                item.labels = existing.labels.clone();
+                item.color = existing.color.clone();
+                item.icon = existing.icon.clone();
+                item.ext = existing.ext.clone();
+                item.valid_from = existing.valid_from;
+                item.valid_until = existing.valid_until;
+            }
+        }
+    }
+}
+
+/// 导入前校验上级，`current`的第一项为导入的目标字典：目标字典不能改上级，
+/// 其余字典的上级须在目标字典下、或在导入文件中排在前面（按顺序新增）；
+/// 同移动字典一样，不能移到自身的下级
+pub fn check_import_parents(
+    current: &[DictTransferDict],
+    imported: &[DictTransferDict],
+) -> Result<(), String> {
+    let Some(root) = current.first() else {
+        return Ok(());
+    };
+    let mut parents: HashMap<&str, Option<&str>> = current
+        .iter()
+        .map(|dict| (dict.dict_code.as_str(), dict.parent_code.as_deref()))
+        .collect();
+    for dict in imported {
+        if dict.dict_code == root.dict_code {
+            if dict.parent_code != root.parent_code {
+                return Err(format!("导入不能修改{}的上级", root.dict_code));
+            }
+            continue;
+        }
+        let parent_code = dict.parent_code.as_deref().unwrap_or_default();
+        if !parents.contains_key(parent_code) {
+            return Err(format!(
+                "字典{}的上级{}不在{}下，或在导入文件中排在它之后",
+                dict.dict_code, parent_code, root.dict_code
+            ));
+        }
+        parents.insert(dict.dict_code.as_str(), Some(parent_code));
+    }
+    for dict in imported {
+        let mut visited = HashSet::new();
+        let mut code = dict.dict_code.as_str();
+        while code != root.dict_code {
+            if !visited.insert(code) {
+                return Err(format!("不能把字典{}移到自身的下级", dict.dict_code));
+            }
+            match parents.get(code) {
+                Some(Some(parent_code)) => code = parent_code,
+                _ => break,
+            }
+        }
+    }
+    Ok(())
+}
+
+fn csv_line(cols: &[&str]) -> String {
+    cols.iter()
+        .map(|col| {
+            if col.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", col.replace('"', "\"\""))
+            } else {
+                col.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn parse_csv(text: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut col = String::new();
+    let mut quoted = false;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(c) = chars.next() {
+        match (quoted, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                col.push('"');
+            }
+            (true, '"') => quoted = false,
+            (true, c) => col.push(c),
+            (false, '"') => quoted = true,
+            (false, ',') => row.push(std::mem::take(&mut col)),
+            (false, '\r') => {}
+            (false, '\n') => {
+                row.push(std::mem::take(&mut col));
+                rows.push(std::mem::take(&mut row));
+            }
+            (false, c) => col.push(c),
+        }
+    }
+    if quoted {
+        return Err("CSV引号未闭合".to_string());
+    }
+    if !col.is_empty() || !row.is_empty() {
+        row.push(col);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DictChangeKind {
+    Added,
+    Changed,
+    /// 当前存在但导入文件中没有，导入不会删除
+    Missing,
+}
+impl Labelable for DictChangeKind {
+    fn label(&self) -> String {
+        match self {
+            DictChangeKind::Added => "新增".to_string(),
+            DictChangeKind::Changed => "修改".to_string(),
+            DictChangeKind::Missing => "文件中缺少".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DictImportPlan {
+    pub dicts: Vec<(DictChangeKind, DictTransferDict)>,
+    /// `(变更, 字典代码, 字典项)`
+    pub items: Vec<(DictChangeKind, String, DictTransferItem)>,
+}
+impl DictImportPlan {
+    /// 对比导入文件与当前字典，`current`为导出时同样范围的字典及字典项
+    pub fn diff(current: &[DictTransferDict], imported: &[DictTransferDict]) -> Self {
+        let current_dicts: HashMap<_, _> = current
+            .iter()
+            .map(|dict| (dict.dict_code.as_str(), dict))
+            .collect();
+        let mut dicts = vec![];
+        let mut items = vec![];
+        for dict in imported {
+            let existing = current_dicts.get(dict.dict_code.as_str());
+            match existing {
+                None => dicts.push((DictChangeKind::Added, dict.clone())),
+                Some(existing) if !existing.same_content(dict) => {
+                    dicts.push((DictChangeKind::Changed, dict.clone()))
+                }
+                _ => {}
+            }
+            let existing_items: HashMap<_, _> = existing
+                .map(|existing| {
+                    existing
+                        .items
+                        .iter()
+                        .map(|item| (item.item_value.as_str(), item))
+                        .collect()
+                })
+                .unwrap_or_default();
+            for item in dict.items.iter() {
+                match existing_items.get(item.item_value.as_str()) {
+                    None => {
+                        items.push((DictChangeKind::Added, dict.dict_code.clone(), item.clone()))
+                    }
+                    Some(existing) if !existing.same_content(item) => items.push((
+                        DictChangeKind::Changed,
+                        dict.dict_code.clone(),
+                        DictTransferItem {
+                            id: existing.id,
+                            ..item.clone()
+                        },
+                    )),
+                    _ => {}
+                }
+            }
+            if let Some(existing) = existing {
+                for item in existing.items.iter() {
+                    if !dict.items.iter().any(|it| it.item_value == item.item_value) {
+                        items.push((
+                            DictChangeKind::Missing,
+                            dict.dict_code.clone(),
+                            item.clone(),
+                        ));
+                    }
+                }
+            }
+        }
+        for dict in current {
+            if !imported.iter().any(|it| it.dict_code == dict.dict_code) {
+                dicts.push((DictChangeKind::Missing, dict.clone()));
+            }
+        }
+        Self { dicts, items }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.dicts
+            .iter()
+            .all(|(kind, _)| *kind == DictChangeKind::Missing)
+            && self
+                .items
+                .iter()
+                .all(|(kind, _, _)| *kind == DictChangeKind::Missing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(item_value: &str, item_name: &str) -> DictTransferItem {
+        DictTransferItem {
+            id: None,
+            item_value: item_value.to_string(),
+            item_name: item_name.to_string(),
+            public: YesNo::Yes,
+            status: YesNo::Yes,
+            remark: None,
+            labels: None,
+            parent_value: None,
+            color: None,
+            icon: None,
+            ext: None,
+            valid_from: None,
+            valid_until: None,
+        }
+    }
+
+    fn dict(dict_code: &str, items: Vec<DictTransferItem>) -> DictTransferDict {
+        DictTransferDict {
+            dict_code: dict_code.to_string(),
+            dict_name: format!("{}名称", dict_code),
+            parent_code: None,
+            public: YesNo::Yes,
+            status: YesNo::Yes,
+            remark: None,
+            labels: None,
+            items,
+        }
+    }
+
+    #[test]
+    fn csv_round_trip() {
+        let dicts = vec![
+            dict(
+                "gender",
+                vec![
+                    DictTransferItem {
+                        remark: Some("含,逗号".to_string()),
+                        ..item("male", "男")
+                    },
+                    DictTransferItem {
+                        remark: Some("第一行\n第二行".to_string()),
+                        parent_value: Some("male".to_string()),
+                        ..item("female", "\"女\"")
+                    },
+                ],
+            ),
+            DictTransferDict {
+                parent_code: Some("gender".to_string()),
+                remark: Some("没有字典项".to_string()),
+                ..dict("empty", vec![])
+            },
+        ];
+        let csv = dicts_to_csv(&dicts);
+        assert_eq!(dicts_from_csv(&csv), Ok(dicts));
+    }
+
+    #[test]
+    fn csv_line_quotes_special_chars() {
+        assert_eq!(
+            csv_line(&["a", "b,c", "d\"e", "f\ng"]),
+            "a,\"b,c\",\"d\"\"e\",\"f\ng\""
+        );
+    }
+
+    #[test]
+    fn csv_with_bom_and_crlf() {
+        let csv = format!(
+            "\u{feff}{}\r\ngender,性别,,,male,男,,\r\ngender,性别,,,female,女,,\r\n",
+            CSV_HEADER.join(",")
+        );
+        let dicts = dicts_from_csv(&csv).unwrap();
+        assert_eq!(dicts.len(), 1);
+        assert_eq!(dicts[0].dict_code, "gender");
+        assert_eq!(
+            dicts[0].items,
+            vec![item("male", "男"), item("female", "女")]
+        );
+    }
+
+    #[test]
+    fn csv_errors() {
+        let header = CSV_HEADER.join(",");
+        assert!(dicts_from_csv(&format!("{}\ngender,\"性别", header)).is_err());
+        assert!(dicts_from_csv(&format!("{}\ngender,性别", header)).is_err());
+        assert!(dicts_from_csv(&format!("{}\n,性别,,,male,男,,", header)).is_err());
+        assert_eq!(
+            dicts_from_csv(&format!("{}\n,,,,,,,\n", header)),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn keep_flags_of_existing() {
+        let current = vec![DictTransferDict {
+            status: YesNo::No,
+            ..dict(
+                "gender",
+                vec![DictTransferItem {
+                    public: YesNo::No,
+                    color: Some("#ff0000".to_string()),
+                    valid_until: Some(100),
+                    ..item("male", "男")
+                }],
+            )
+        }];
+        let mut imported = vec![dict(
+            "gender",
+            vec![item("male", "男士"), item("female", "女")],
+        )];
+        keep_current_flags(&mut imported, &current);
+        assert_eq!(imported[0].status, YesNo::No);
+        assert_eq!(imported[0].items[0].public, YesNo::No);
+        assert_eq!(imported[0].items[0].color.as_deref(), Some("#ff0000"));
+        assert_eq!(imported[0].items[0].valid_until, Some(100));
+        assert_eq!(imported[0].items[0].item_name, "男士");
+        assert_eq!(imported[0].items[1], item("female", "女"));
+    }
+
+    #[test]
+    fn diff_classifies_changes() {
+        let current = vec![
+            dict(
+                "gender",
+                vec![
+                    DictTransferItem {
+                        id: Some(1),
+                        ..item("male", "男")
+                    },
+                    DictTransferItem {
+                        id: Some(2),
+                        ..item("female", "女")
+                    },
+                    DictTransferItem {
+                        id: Some(3),
+                        ..item("other", "其他")
+                    },
+                ],
+            ),
+            dict("removed", vec![]),
+        ];
+        let imported = vec![
+            DictTransferDict {
+                dict_name: "性别".to_string(),
+                ..dict(
+                    "gender",
+                    vec![
+                        item("male", "男"),
+                        item("female", "女士"),
+                        item("unknown", "未知"),
+                    ],
+                )
+            },
+            dict("added", vec![item("a", "A")]),
+        ];
+        let plan = DictImportPlan::diff(&current, &imported);
+        let dicts: Vec<_> = plan
+            .dicts
+            .iter()
+            .map(|(kind, dict)| (*kind, dict.dict_code.as_str()))
+            .collect();
+        assert_eq!(
+            dicts,
+            vec![
+                (DictChangeKind::Changed, "gender"),
+                (DictChangeKind::Added, "added"),
+                (DictChangeKind::Missing, "removed"),
+            ]
+        );
+        let items: Vec<_> = plan
+            .items
+            .iter()
+            .map(|(kind, dict_code, item)| {
+                (*kind, dict_code.as_str(), item.item_value.as_str(), item.id)
+            })
+            .collect();
+        assert_eq!(
+            items,
+            vec![
+                (DictChangeKind::Changed, "gender", "female", Some(2)),
+                (DictChangeKind::Added, "gender", "unknown", None),
+                (DictChangeKind::Missing, "gender", "other", Some(3)),
+                (DictChangeKind::Added, "added", "a", None),
+            ]
+        );
+        assert!(!plan.is_empty());
+    }
+
+    #[test]
+    fn json_without_labels_keeps_current() {
+        let imported: Vec<DictTransferDict> = serde_json::from_str(
+            r#"[{"dict_code":"gender","dict_name":"gender名称","items":[{"item_value":"male","item_name":"男"}]}]"#,
+        )
+        .unwrap();
+        assert_eq!(imported[0].labels, None);
+        assert_eq!(imported[0].items[0].labels, None);
+        let labels = LocaleLabels::from([("en".to_string(), "Male".to_string())]);
+        let current = vec![DictTransferDict {
+            labels: Some(LocaleLabels::new()),
+            ..dict(
+                "gender",
+                vec![DictTransferItem {
+                    id: Some(1),
+                    labels: Some(labels.clone()),
+                    ..item("male", "男")
+                }],
+            )
+        }];
+        assert!(DictImportPlan::diff(&current, &imported).is_empty());
+        let imported = vec![dict(
+            "gender",
+            vec![DictTransferItem {
+                labels: Some(LocaleLabels::new()),
+                ..item("male", "男")
+            }],
+        )];
+        let plan = DictImportPlan::diff(&current, &imported);
+        assert_eq!(plan.items.len(), 1);
+        assert_eq!(plan.items[0].0, DictChangeKind::Changed);
+    }
+
+    fn child(dict_code: &str, parent_code: &str) -> DictTransferDict {
+        DictTransferDict {
+            parent_code: Some(parent_code.to_string()),
+            ..dict(dict_code, vec![])
+        }
+    }
+
+    #[test]
+    fn import_parents_within_subtree() {
+        let current = vec![
+            child("region", "root"),
+            child("province", "region"),
+            child("city", "province"),
+        ];
+        let imported = vec![
+            child("region", "root"),
+            child("district", "region"),
+            child("street", "district"),
+            child("city", "region"),
+        ];
+        assert_eq!(check_import_parents(&current, &imported), Ok(()));
+        assert_eq!(check_import_parents(&[], &imported), Ok(()));
+    }
+
+    #[test]
+    fn import_parents_rejects_outside_or_reordered() {
+        let current = vec![child("region", "root"), child("province", "region")];
+        assert!(check_import_parents(&current, &[child("region", "other")]).is_err());
+        assert!(check_import_parents(&current, &[child("province", "other")]).is_err());
+        assert!(check_import_parents(&current, &[dict("province", vec![])]).is_err());
+        assert!(check_import_parents(
+            &current,
+            &[child("street", "district"), child("district", "region")]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn import_parents_rejects_cycles() {
+        let current = vec![
+            child("region", "root"),
+            child("province", "region"),
+            child("city", "province"),
+        ];
+        assert_eq!(
+            check_import_parents(&current, &[child("province", "city")]),
+            Err("不能把字典province移到自身的下级".to_string())
+        );
+        assert!(check_import_parents(&current, &[child("province", "province")]).is_err());
+    }
+
+    #[test]
+    fn diff_of_same_content_is_empty() {
+        let current = vec![dict(
+            "gender",
+            vec![DictTransferItem {
+                id: Some(1),
+                ..item("male", "男")
+            }],
+        )];
+        let imported = vec![dict("gender", vec![item("male", "男")])];
+        let plan = DictImportPlan::diff(&current, &imported);
+        assert!(plan.dicts.is_empty());
+        assert!(plan.items.is_empty());
+        assert!(plan.is_empty());
+    }
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+use super::DictTransferItem;
+
+/// 字典的已发布版本。对字典项的修改先进入草稿，发布后生成新版本；
+/// 客户端按`dict_code`和`version`获取，已发布的版本不会再变，可以缓存
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DictVersionListItem {
+    pub id: i32,
+    pub dict_code: String,
+    pub version: i32,
+    pub remark: Option<String>,
+    pub publisher: String,
+    pub published: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DictVersionListReq {
+    pub dict_code: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DictVersionReq {
+    pub dict_code: String,
+    pub version: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DictVersionDetail {
+    #[serde(flatten)]
+    pub version: DictVersionListItem,
+    pub items: Vec<DictTransferItem>,
+}
+
+/// 把当前草稿发布为新版本
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublishDictReq {
+    pub dict_code: String,
+    pub remark: Option<String>,
+}
+
+/// 回滚：草稿恢复为`version`的内容，并发布为新版本
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollbackDictReq {
+    pub dict_code: String,
+    pub version: i32,
+}
+
+/// 两个版本之间一个字典项的差异，`before`为空是新增，`after`为空是删除
+#[derive(Debug, Clone)]
+pub struct DictItemDiff {
+    pub item_value: String,
+    pub before: Option<DictTransferItem>,
+    pub after: Option<DictTransferItem>,
+}
+impl DictItemDiff {
+    pub fn label(&self) -> &'static str {
+        match (&self.before, &self.after) {
+            (None, _) => "新增",
+            (_, None) => "删除",
+            _ => "修改",
+        }
+    }
+    pub fn diff(before: &[DictTransferItem], after: &[DictTransferItem]) -> Vec<DictItemDiff> {
+        let mut diffs = vec![];
+        for item in after {
+            let existing = before.iter().find(|it| it.item_value == item.item_value);
+            if existing.is_some_and(|existing| {
+                existing.same_content(item) && existing.parent_value == item.parent_value
+            }) {
+                continue;
+            }
+            diffs.push(DictItemDiff {
+                item_value: item.item_value.clone(),
+                before: existing.cloned(),
+                after: Some(item.clone()),
+            });
+        }
+        for item in before {
+            if !after.iter().any(|it| it.item_value == item.item_value) {
+                diffs.push(DictItemDiff {
+                    item_value: item.item_value.clone(),
+                    before: Some(item.clone()),
+                    after: None,
+                });
+            }
+        }
+        diffs
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use tower::tauri_web::prelude::*;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FlagKind {
+    #[default]
+    Boolean,
+    Multivariant,
+}
+impl FlagKind {
+    pub fn all() -> Vec<FlagKind> {
+        vec![FlagKind::Boolean, FlagKind::Multivariant]
+    }
+}
+impl Labelable for FlagKind {
+    fn label(&self) -> String {
+        match self {
+            FlagKind::Boolean => "开关".to_string(),
+            FlagKind::Multivariant => "多变体".to_string(),
+        }
+    }
+}
+impl Display for FlagKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FlagKind::Boolean => write!(f, "Boolean"),
+            FlagKind::Multivariant => write!(f, "Multivariant"),
+        }
+    }
+}
+impl TryFrom<String> for FlagKind {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "Boolean" => Ok(FlagKind::Boolean),
+            "Multivariant" => Ok(FlagKind::Multivariant),
+            _ => Err(format!("未知的开关类型：{}", value)),
+        }
+    }
+}
+
+/// 多变体开关的变体，`weight`为百分比，所有变体之和为100
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlagVariant {
+    pub value: String,
+    pub weight: u32,
+}
+
+/// 功能开关，评估顺序：定向用户、用户组、会员等级 -> 灰度百分比 -> 默认变体
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlagListItem {
+    pub id: i32,
+    pub app: App,
+    pub flag_key: String,
+    pub flag_name: String,
+    pub kind: FlagKind,
+    pub variants: Vec<FlagVariant>,
+    pub default_variant: String,
+    pub rollout: u32,
+    pub target_user_ids: Vec<String>,
+    pub target_group_ids: Vec<String>,
+    pub target_vip_levels: Vec<String>,
+    pub target_variant: Option<String>,
+    pub remark: Option<String>,
+    pub status: YesNo,
+    pub created: i64,
+    pub updated: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlagListReq {
+    pub app_id: String,
+    pub flag_key: String,
+    pub status: Option<YesNo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveFlagReq {
+    pub id: Option<i32>,
+    pub app_id: String,
+    pub flag_key: String,
+    pub flag_name: String,
+    pub kind: FlagKind,
+    pub variants: Vec<FlagVariant>,
+    pub default_variant: String,
+    pub rollout: u32,
+    pub target_user_ids: Vec<String>,
+    pub target_group_ids: Vec<String>,
+    pub target_vip_levels: Vec<String>,
+    pub target_variant: Option<String>,
+    pub remark: Option<String>,
+    pub status: YesNo,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvaluateFlagReq {
+    pub flag_id: i32,
+    pub user_id: String,
+}
+
+/// `reason`为命中说明，如"定向用户"、"灰度 37/50"
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EvaluateFlagResp {
+    pub variant: String,
+    pub reason: String,
+}
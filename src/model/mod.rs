@@ -0,0 +1,32 @@
+mod config;
+pub use config::*;
+
+mod flag;
+pub use flag::*;
+
+mod dict;
+pub use dict::*;
+
+mod dict_transfer;
+pub use dict_transfer::*;
+
+mod dict_codegen;
+pub use dict_codegen::*;
+
+mod dict_version;
+pub use dict_version::*;
+
+mod approval;
+pub use approval::*;
+
+mod template;
+pub use template::*;
+
+mod audit;
+pub use audit::*;
+
+use tower::tauri_web::prelude::*;
+
+pub(crate) fn default_yes() -> YesNo {
+    YesNo::Yes
+}
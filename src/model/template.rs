@@ -0,0 +1,104 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use tower::tauri_web::prelude::*;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ConfigValueType {
+    #[default]
+    String,
+    Number,
+    Bool,
+    Json,
+}
+impl ConfigValueType {
+    pub fn all() -> Vec<ConfigValueType> {
+        vec![
+            ConfigValueType::String,
+            ConfigValueType::Number,
+            ConfigValueType::Bool,
+            ConfigValueType::Json,
+        ]
+    }
+    pub fn validate(&self, value: &str) -> bool {
+        match self {
+            ConfigValueType::String => true,
+            ConfigValueType::Number => value.trim().parse::<f64>().is_ok(),
+            ConfigValueType::Bool => matches!(value.trim(), "true" | "false"),
+            ConfigValueType::Json => serde_json::from_str::<serde_json::Value>(value).is_ok(),
+        }
+    }
+}
+impl Labelable for ConfigValueType {
+    fn label(&self) -> String {
+        match self {
+            ConfigValueType::String => "字符串".to_string(),
+            ConfigValueType::Number => "数字".to_string(),
+            ConfigValueType::Bool => "布尔".to_string(),
+            ConfigValueType::Json => "JSON".to_string(),
+        }
+    }
+}
+impl Display for ConfigValueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigValueType::String => write!(f, "String"),
+            ConfigValueType::Number => write!(f, "Number"),
+            ConfigValueType::Bool => write!(f, "Bool"),
+            ConfigValueType::Json => write!(f, "Json"),
+        }
+    }
+}
+impl TryFrom<String> for ConfigValueType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "String" => Ok(ConfigValueType::String),
+            "Number" => Ok(ConfigValueType::Number),
+            "Bool" => Ok(ConfigValueType::Bool),
+            "Json" => Ok(ConfigValueType::Json),
+            _ => Err(format!("未知的值类型：{}", value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigTemplateItem {
+    pub var_name: String,
+    pub var_value: String,
+    pub value_type: ConfigValueType,
+    pub remark: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigTemplateListItem {
+    pub id: i32,
+    pub template_name: String,
+    pub items: Vec<ConfigTemplateItem>,
+    pub remark: Option<String>,
+    pub created: i64,
+    pub updated: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveConfigTemplateReq {
+    pub id: Option<i32>,
+    pub template_name: String,
+    pub items: Vec<ConfigTemplateItem>,
+    pub remark: Option<String>,
+}
+
+/// 应用模板：目标应用及版本下已存在的同名配置跳过
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplyConfigTemplateReq {
+    pub template_id: i32,
+    pub app_id: String,
+    pub app_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApplyConfigTemplateResp {
+    pub created: Vec<String>,
+    pub skipped: Vec<String>,
+}
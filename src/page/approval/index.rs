@@ -0,0 +1,156 @@
+use leptos::prelude::*;
+use tower::tauri_web::prelude::*;
+
+use crate::{
+    model::{ChangeRequestStatus, ConfigChangeListItem, ConfigChangeListReq},
+    service::async_get_config_changes,
+};
+
+use super::*;
+
+#[component]
+pub fn ApprovalPane() -> impl IntoView {
+    let status = RwSignal::new(Some(ChangeRequestStatus::Pending));
+    let changes_resource = LocalResource::new(move || {
+        async_get_config_changes(ConfigChangeListReq {
+            status: status.get(),
+        })
+    });
+    let current_change = RwSignal::new(None::<ConfigChangeListItem>);
+    let open_review = RwSignal::new(false);
+    view! {
+        <div class="flex gap-4">
+            <div role="tablist" class="tabs tabs-box">
+                {ChangeRequestStatus::all()
+                    .into_iter()
+                    .map(|item| {
+                        view! {
+                            <a
+                                role="tab"
+                                class="tab"
+                                class:tab-active=move || status.get() == Some(item)
+                                on:click=move |_| status.set(Some(item))
+                            >
+                                {item.label()}
+                            </a>
+                        }
+                    })
+                    .collect_view()}
+                <a
+                    role="tab"
+                    class="tab"
+                    class:tab-active=move || status.get().is_none()
+                    on:click=move |_| status.set(None)
+                >
+                    全部
+                </a>
+            </div>
+            <button
+                class="btn"
+                on:click=move |_| {
+                    changes_resource.refetch();
+                }
+            >
+                刷新
+            </button>
+        </div>
+        <table class="table">
+            <thead>
+                <tr>
+                    <th></th>
+                    <th>应用</th>
+                    <th>配置名称</th>
+                    <th>变更内容</th>
+                    <th>变更说明</th>
+                    <th>申请人</th>
+                    <th>申请时间</th>
+                    <th>状态</th>
+                    <th>审批人</th>
+                    <th>审批意见</th>
+                    <th>操作</th>
+                </tr>
+            </thead>
+            <tbody>
+                <Suspense fallback=move || {
+                    view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+                }>
+                    {move || {
+                        changes_resource
+                            .to_view(|list| {
+                                list.into_iter()
+                                    .enumerate()
+                                    .map(|(index, change)| {
+                                        let change = change.clone();
+                                        let pending = change.status == ChangeRequestStatus::Pending;
+                                        view! {
+                                            <tr>
+                                                <th>{index + 1}</th>
+                                                <td>{change.app.label()}</td>
+                                                <td>{change.before.var_name.clone()}</td>
+                                                <td>
+                                                    <ConfigChangeDiff change=change.clone() />
+                                                </td>
+                                                <td>{change.reason.clone()}</td>
+                                                <td>{change.requester.clone()}</td>
+                                                <td>{from_secs_and_default(change.created)}</td>
+                                                <td>{change.status.label()}</td>
+                                                <td>{change.reviewer.clone()}</td>
+                                                <td>{change.review_comment.clone()}</td>
+                                                <td>
+                                                    <Show when=move || pending>
+                                                        <button
+                                                            class="btn btn-xs"
+                                                            on:click={
+                                                                let change = change.clone();
+                                                                move |_| {
+                                                                    current_change.set(Some(change.clone()));
+                                                                    open_review.set(true)
+                                                                }
+                                                            }
+                                                        >
+                                                            审批
+                                                        </button>
+                                                    </Show>
+                                                </td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view()
+                                    .into_any()
+                            })
+                    }}
+                </Suspense>
+            </tbody>
+        </table>
+        <Show when=move || { current_change.get().is_some() && open_review.get() }>
+            <Dialog open=open_review title="审批变更">
+                <ReviewChangeForm open=open_review changes_resource change=current_change />
+            </Dialog>
+        </Show>
+    }
+}
+
+#[component]
+pub fn ConfigChangeDiff(#[prop(into)] change: Signal<ConfigChangeListItem>) -> impl IntoView {
+    let change = change.get_untracked();
+    view! {
+        <ul class="text-sm">
+            {change
+                .before
+                .diff(&change.after)
+                .into_iter()
+                .map(|(field, before, after)| {
+                    view! {
+                        <li>
+                            <span class="font-bold">{field}</span>
+                            ": "
+                            <span class="line-through opacity-50">{before}</span>
+                            " → "
+                            <span>{after}</span>
+                        </li>
+                    }
+                })
+                .collect_view()}
+        </ul>
+    }
+}
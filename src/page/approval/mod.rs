@@ -0,0 +1,5 @@
+mod index;
+pub use index::*;
+
+mod review_change;
+pub use review_change::*;
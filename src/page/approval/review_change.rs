@@ -0,0 +1,71 @@
+use leptos::{prelude::*, task::spawn_local};
+use tower::tauri_web::prelude::*;
+
+use crate::{
+    model::{ConfigChangeListItem, ReviewConfigChangeReq},
+    page::ConfigChangeDiff,
+    service::async_review_config_change,
+};
+
+#[component]
+pub(crate) fn ReviewChangeForm(
+    open: RwSignal<bool>,
+    changes_resource: WebResult<Vec<ConfigChangeListItem>>,
+    change: RwSignal<Option<ConfigChangeListItem>>,
+) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    let change = change.get_untracked().unwrap();
+    let id = change.id;
+    let comment = RwSignal::new("".to_string());
+    let op_tiper = OpTiper::new();
+    let review = move |approved: bool| {
+        let req = ReviewConfigChangeReq {
+            id,
+            approved,
+            comment: str_trim_to_option(comment.get_untracked()),
+        };
+        spawn_local(async move {
+            let resp = async_review_config_change(req).await;
+            tip_or(resp, op_tiper.0, |_| {
+                changes_resource.refetch();
+                open.set(false);
+                app_state.success(if approved {
+                    "已通过。"
+                } else {
+                    "已驳回。"
+                });
+            });
+        });
+    };
+    view! {
+        <fieldset class="p-4 w-full fieldset">
+            <label class="label">应用</label>
+            <span>{change.app.label()}</span>
+            <label class="label">配置名称</label>
+            <span>{change.before.var_name.clone()}</span>
+            <label class="label">申请人</label>
+            <span>{change.requester.clone()}</span>
+            <label class="label">变更说明</label>
+            <span>{change.reason.clone()}</span>
+            <label class="label">变更内容</label>
+            <ConfigChangeDiff change=change.clone() />
+            <label class="mt-4 mb-2 label">审批意见</label>
+            <textarea
+                class="w-full textarea"
+                maxlength="500"
+                on:input:target=move |ev| { comment.set(ev.target().value()) }
+            >
+                {move || comment.get_untracked()}
+            </textarea>
+            <OpTip content=op_tiper.0 />
+            <div class="flex gap-4 mt-4">
+                <button class="btn btn-neutral" on:click=move |_| review(true)>
+                    通过
+                </button>
+                <button class="btn" on:click=move |_| review(false)>
+                    驳回
+                </button>
+            </div>
+        </fieldset>
+    }
+}
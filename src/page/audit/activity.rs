@@ -0,0 +1,70 @@
+use leptos::prelude::*;
+use tower::tauri_web::prelude::*;
+
+use crate::{model::AuditResourceType, page::AuditChanges, service::async_get_activities};
+
+/// 单个对象的操作记录，`target_id`为空时不查询；`child_types`为归属于该对象的子资源，如字典的字典项
+#[component]
+pub fn ActivityPane(
+    resource_types: Vec<AuditResourceType>,
+    #[prop(optional)] child_types: Vec<AuditResourceType>,
+    #[prop(into)] target_id: Signal<Option<String>>,
+) -> impl IntoView {
+    let activities_resource = LocalResource::new(move || {
+        async_get_activities(resource_types.clone(), child_types.clone(), target_id.get())
+    });
+    view! {
+        <div class="flex items-center">
+            <label class="menu-title">操作记录</label>
+            <div>
+                <button
+                    class="btn btn-xs"
+                    disabled=move || target_id.get().is_none()
+                    on:click=move |_| {
+                        activities_resource.refetch();
+                    }
+                >
+                    刷新
+                </button>
+            </div>
+        </div>
+        <Suspense fallback=move || {
+            view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+        }>
+            {move || {
+                activities_resource
+                    .to_view(|list| {
+                        if list.is_empty() {
+                            return view! { <p class="px-4 text-sm opacity-60">暂无操作记录</p> }
+                                .into_any();
+                        }
+                        view! {
+                            <ul class="px-4 text-sm">
+                                {list
+                                    .into_iter()
+                                    .map(|audit| {
+                                        view! {
+                                            <li class="flex gap-4 py-1 border-b border-base-200">
+                                                <span class="flex-none w-40 opacity-60">
+                                                    {from_secs_and_default(audit.created)}
+                                                </span>
+                                                <span class="flex-none w-48">
+                                                    <span class="font-bold">{audit.actor.clone()}</span>
+                                                    " "
+                                                    {audit.action.label()}
+                                                    " "
+                                                    {audit.resource_type.label()}
+                                                </span>
+                                                <AuditChanges audit=audit.clone() />
+                                            </li>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </ul>
+                        }
+                            .into_any()
+                    })
+            }}
+        </Suspense>
+    }
+}
@@ -0,0 +1,260 @@
+use leptos::prelude::*;
+use tower::{
+    tauri_web::prelude::*,
+    web_model::{OpTipData, OpTipType},
+};
+
+use crate::{
+    model::{audit_changes, AuditListItem, AuditListReq, AuditResourceType},
+    page::datetime_local_to_secs,
+    service::async_get_audits,
+};
+
+#[component]
+pub fn AuditPane() -> impl IntoView {
+    let query_form = AuditQueryFormData::new();
+    let query = RwSignal::new(AuditListReq {
+        limit: 100,
+        ..Default::default()
+    });
+    let audits_resource = LocalResource::new(move || async_get_audits(query.get()));
+    let op_tiper = OpTiper::new();
+    view! {
+        <div class="flex flex-wrap gap-4 items-center">
+            <label class="floating-label">
+                <span>操作人</span>
+                <input
+                    type="text"
+                    placeholder="操作人"
+                    class="input input-md"
+                    bind:value=query_form.actor
+                />
+            </label>
+            <select
+                class="select"
+                on:change:target=move |ev| {
+                    query_form.resource_type.set(ev.target().value().try_into().ok());
+                }
+                prop:value=move || {
+                    query_form.resource_type.get().map(|it| it.to_string()).unwrap_or_default()
+                }
+            >
+                <option value="">全部资源</option>
+                {AuditResourceType::all()
+                    .into_iter()
+                    .map(|item| {
+                        view! { <option value=item.to_string()>{item.label()}</option> }
+                    })
+                    .collect_view()}
+            </select>
+            <label class="floating-label">
+                <span>目标id</span>
+                <input
+                    type="text"
+                    placeholder="目标id"
+                    class="input input-md"
+                    bind:value=query_form.target_id
+                />
+            </label>
+            <div class="flex gap-2 items-center">
+                <input type="datetime-local" class="input" bind:value=query_form.from />
+                <span>至</span>
+                <input type="datetime-local" class="input" bind:value=query_form.until />
+            </div>
+            <select
+                class="w-24 select"
+                on:change:target=move |ev| {
+                    query_form.limit.set(ev.target().value().parse().unwrap());
+                }
+                prop:value=move || query_form.limit.get().to_string()
+            >
+                {[50, 100, 200, 500]
+                    .into_iter()
+                    .map(|i| {
+                        view! { <option value=i>{format!("{}条", i)}</option> }
+                    })
+                    .collect_view()}
+            </select>
+            <button
+                class="btn"
+                on:click=move |_| {
+                    match query_form.to_req() {
+                        Ok(req) => {
+                            op_tiper.0.set(None);
+                            query.set(req);
+                        }
+                        Err(content) => {
+                            op_tiper
+                                .0
+                                .set(
+                                    Some(OpTipData {
+                                        typ: OpTipType::Warning,
+                                        class: "".into(),
+                                        content,
+                                    }),
+                                );
+                        }
+                    }
+                }
+            >
+                查询
+            </button>
+            <button
+                class="btn"
+                on:click=move |_| {
+                    query_form.reset();
+                }
+            >
+                重置
+            </button>
+            <button
+                class="btn"
+                on:click=move |_| {
+                    audits_resource.refetch();
+                }
+            >
+                刷新
+            </button>
+        </div>
+        <OpTip content=op_tiper.0 />
+        <table class="table">
+            <thead>
+                <tr>
+                    <th></th>
+                    <th>时间</th>
+                    <th>操作人</th>
+                    <th>操作</th>
+                    <th>资源</th>
+                    <th>目标id</th>
+                    <th>变更内容</th>
+                    <th>客户端</th>
+                </tr>
+            </thead>
+            <tbody>
+                <Suspense fallback=move || {
+                    view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+                }>
+                    {move || {
+                        audits_resource
+                            .to_view(|list| {
+                                list.into_iter()
+                                    .enumerate()
+                                    .map(|(index, audit)| {
+                                        view! {
+                                            <tr>
+                                                <th>{index + 1}</th>
+                                                <td>{from_secs_and_default(audit.created)}</td>
+                                                <td>{audit.actor.clone()}</td>
+                                                <td>{audit.action.label()}</td>
+                                                <td>{audit.resource_type.label()}</td>
+                                                <td>{audit.target_id.clone()}</td>
+                                                <td>
+                                                    <AuditChanges audit=audit.clone() />
+                                                </td>
+                                                <td title=audit.client.user_agent.clone()>
+                                                    {audit.client.app.label()}
+                                                    <span class="block text-xs opacity-60">
+                                                        {audit.ip.clone()}
+                                                    </span>
+                                                </td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view()
+                                    .into_any()
+                            })
+                    }}
+                </Suspense>
+            </tbody>
+        </table>
+    }
+}
+
+/// 折叠显示变更前后不同的字段
+#[component]
+pub fn AuditChanges(audit: AuditListItem) -> impl IntoView {
+    let changes = audit_changes(audit.before.as_ref(), audit.after.as_ref());
+    if changes.is_empty() {
+        return view! { <span class="opacity-60">无</span> }.into_any();
+    }
+    view! {
+        <details>
+            <summary class="cursor-pointer">{format!("{}个字段", changes.len())}</summary>
+            <ul class="text-sm">
+                {changes
+                    .into_iter()
+                    .map(|(field, before, after)| {
+                        view! {
+                            <li>
+                                <span class="font-bold">{field}</span>
+                                ": "
+                                <span class="line-through opacity-50">
+                                    {audit_value_text(before)}
+                                </span>
+                                " → "
+                                <span>{audit_value_text(after)}</span>
+                            </li>
+                        }
+                    })
+                    .collect_view()}
+            </ul>
+        </details>
+    }
+    .into_any()
+}
+
+fn audit_value_text(value: Option<serde_json::Value>) -> String {
+    match value {
+        None | Some(serde_json::Value::Null) => "".to_string(),
+        Some(serde_json::Value::String(value)) => value,
+        Some(value) => value.to_string(),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct AuditQueryFormData {
+    actor: RwSignal<String>,
+    resource_type: RwSignal<Option<AuditResourceType>>,
+    target_id: RwSignal<String>,
+    from: RwSignal<String>,
+    until: RwSignal<String>,
+    limit: RwSignal<u32>,
+}
+impl AuditQueryFormData {
+    fn new() -> Self {
+        Self {
+            actor: RwSignal::new("".to_string()),
+            resource_type: RwSignal::new(None),
+            target_id: RwSignal::new("".to_string()),
+            from: RwSignal::new("".to_string()),
+            until: RwSignal::new("".to_string()),
+            limit: RwSignal::new(100),
+        }
+    }
+    fn reset(&self) {
+        self.actor.set("".to_string());
+        self.resource_type.set(None);
+        self.target_id.set("".to_string());
+        self.from.set("".to_string());
+        self.until.set("".to_string());
+        self.limit.set(100);
+    }
+    fn to_req(&self) -> Result<AuditListReq, String> {
+        let from = datetime_local_to_secs(&self.from.get_untracked());
+        let until = datetime_local_to_secs(&self.until.get_untracked());
+        if let (Some(from), Some(until)) = (from, until) {
+            if from > until {
+                return Err("开始时间不能晚于结束时间".to_string());
+            }
+        }
+        Ok(AuditListReq {
+            actor: str_trim_to_option(self.actor.get_untracked()),
+            resource_types: self.resource_type.get_untracked().into_iter().collect(),
+            target_id: str_trim_to_option(self.target_id.get_untracked()),
+            child_types: vec![],
+            from,
+            until,
+            limit: self.limit.get_untracked(),
+        })
+    }
+}
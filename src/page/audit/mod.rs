@@ -0,0 +1,5 @@
+mod index;
+pub use index::*;
+
+mod activity;
+pub use activity::*;
@@ -0,0 +1,71 @@
+use leptos::prelude::*;
+use tower::tauri_web::prelude::*;
+
+use crate::{model::DictItemValidity, service::async_get_dict_items};
+
+/// 取值限定为字典项的下拉框，`selected`为字典项的值；无效或不在有效期内的字典项不可选，
+/// 但打开时已选中的值保持可选并提示，不影响原样保存
+#[component]
+pub fn DictValueSelect(
+    #[prop(into,default="".into())] class: Signal<String>,
+    #[prop(into)] dict_code: Signal<Option<String>>,
+    selected: RwSignal<String>,
+) -> impl IntoView {
+    let items_resource = LocalResource::new(move || async_get_dict_items(dict_code.get()));
+    let initial = selected.get_untracked();
+    view! {
+        <Suspense fallback=move || {
+            view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+        }>
+            {move || {
+                let initial = initial.clone();
+                items_resource
+                    .to_view(move |items| {
+                        let now_secs = (js_sys::Date::now() / 1000.0) as i64;
+                        let inactive: Vec<String> = items
+                            .iter()
+                            .filter(|item| {
+                                item.status != YesNo::Yes
+                                    || item.validity(now_secs) != DictItemValidity::Active
+                            })
+                            .map(|item| item.item_value.clone())
+                            .collect();
+                        let selected_inactive = {
+                            let inactive = inactive.clone();
+                            move || selected.with(|selected| inactive.contains(selected))
+                        };
+                        view! {
+                            <select
+                                class=move || format!("select validator {}", class.get())
+                                required
+                                on:change:target=move |ev| {
+                                    selected.set(ev.target().value());
+                                }
+                                prop:value=move || selected.get()
+                            >
+                                <option disabled value="">
+                                    选择字典项
+                                </option>
+                                {items
+                                    .into_iter()
+                                    .map(|item| {
+                                        let disabled = item.item_value != initial
+                                            && inactive.contains(&item.item_value);
+                                        view! {
+                                            <option value=item.item_value.clone() disabled=disabled>
+                                                {format!("{}（{}）", item.item_name, item.item_value)}
+                                            </option>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </select>
+                            <Show when=selected_inactive>
+                                <p class="text-warning">当前值已停用或不在有效期内</p>
+                            </Show>
+                        }
+                            .into_any()
+                    })
+            }}
+        </Suspense>
+    }
+}
@@ -0,0 +1,65 @@
+use leptos::prelude::*;
+
+use crate::model::{missing_locales, LocaleLabels, LOCALES};
+
+/// 按语言切换编辑名称，第一个页签为中文名称`name`，其余写入`labels`
+#[component]
+pub fn LocaleTabs(name: RwSignal<String>, labels: RwSignal<LocaleLabels>) -> impl IntoView {
+    let locale = RwSignal::new(None::<&'static str>);
+    let tab_class = move |tab: Option<&'static str>| {
+        let missing = tab.is_some_and(|tab| missing_locales(&labels.get()).contains(&tab));
+        match (locale.get() == tab, missing) {
+            (true, _) => "tab tab-active",
+            (false, true) => "tab text-warning",
+            (false, false) => "tab",
+        }
+    };
+    view! {
+        <div role="tablist" class="tabs tabs-border">
+            <a role="tab" class=move || tab_class(None) on:click=move |_| locale.set(None)>
+                中文
+            </a>
+            {LOCALES
+                .iter()
+                .map(|(code, locale_name)| {
+                    let code = *code;
+                    view! {
+                        <a
+                            role="tab"
+                            class=move || tab_class(Some(code))
+                            on:click=move |_| locale.set(Some(code))
+                        >
+                            {*locale_name}
+                        </a>
+                    }
+                })
+                .collect_view()}
+        </div>
+        <input
+            type="text"
+            class="w-full input validator"
+            maxlength="50"
+            required=move || locale.get().is_none()
+            prop:value=move || match locale.get() {
+                None => name.get(),
+                Some(code) => labels.with(|labels| labels.get(code).cloned().unwrap_or_default()),
+            }
+            on:input:target=move |ev| {
+                let value = ev.target().value();
+                match locale.get_untracked() {
+                    None => name.set(value),
+                    Some(code) => {
+                        labels
+                            .update(|labels| {
+                                if value.trim().is_empty() {
+                                    labels.remove(code);
+                                } else {
+                                    labels.insert(code.to_string(), value);
+                                }
+                            })
+                    }
+                }
+            }
+        />
+    }
+}
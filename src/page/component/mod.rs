@@ -6,3 +6,9 @@ pub use status_checkbox::*;
 
 mod status_select;
 pub use status_select::*;
+
+mod dict_value_select;
+pub use dict_value_select::*;
+
+mod locale_tabs;
+pub use locale_tabs::*;
@@ -1,22 +1,25 @@
 use leptos::{prelude::*, task::spawn_local};
-use tower::{
-    management_model::{AddConfigReq, ConfigListItem},
-    tauri_web::prelude::*,
-};
+use leptos_use::signal_debounced;
+use tower::{management_model::AddConfigReq, tauri_web::prelude::*};
 
 use crate::{
-    page::component::{AppSelect, StatusSelect},
+    model::{AddConfigReqExt, ConfigListItemExt, APP_VERSION_PATTERN},
+    page::component::{AppSelect, DictValueSelect, StatusSelect},
     service::async_add_config,
 };
 
 #[component]
 pub(crate) fn AddConfigForm(
     open: RwSignal<bool>,
-    configs_resource: WebResult<Vec<ConfigListItem>>,
-    current_clone: RwSignal<Option<ConfigListItem>>,
+    configs_resource: WebResult<Vec<ConfigListItemExt>>,
+    current_clone: RwSignal<Option<ConfigListItemExt>>,
 ) -> impl IntoView {
     let app_state = expect_context::<AppState>();
     let form = AddConfigFormData::new(current_clone.get_untracked());
+    let dict_code = Signal::derive({
+        let dict_code = signal_debounced(form.dict_code, 500.0);
+        move || str_trim_to_option(dict_code.get())
+    });
     let op_tiper = OpTiper::new();
     view! {
         <form on:submit=move |ev| {
@@ -39,6 +42,16 @@ pub(crate) fn AddConfigForm(
                 <label class="label">应用</label>
                 <AppSelect selected=form.app />
                 <p class="validator-hint">必须输入</p>
+                <label class="label">应用版本</label>
+                <input
+                    type="text"
+                    class="w-full input validator"
+                    maxlength="20"
+                    pattern=APP_VERSION_PATTERN
+                    placeholder="留空对所有版本生效，如 >=1.4、1.3.2"
+                    bind:value=form.app_version
+                />
+                <p class="validator-hint">"版本格式：[>=|>|<=|<|=]主版本[.次版本[.修订号]]"</p>
                 <label class="label">配置名称</label>
                 <input
                     type="text"
@@ -49,19 +62,44 @@ pub(crate) fn AddConfigForm(
                     bind:value=form.var_name
                 />
                 <p class="validator-hint">必须输入</p>
-                <label class="label">配置值</label>
+                <label class="label">引用字典</label>
                 <input
                     type="text"
-                    class="w-full input validator"
-                    minlength="1"
+                    class="w-full input"
                     maxlength="50"
-                    required
-                    bind:value=form.var_value
+                    placeholder="字典代码，填写后配置值只能从字典项中选择"
+                    bind:value=form.dict_code
                 />
+                <label class="label">配置值</label>
+                <Show
+                    when=move || dict_code.get().is_some() && form.secret.get() == YesNo::No
+                    fallback=move || {
+                        view! {
+                            <input
+                                type=move || {
+                                    if form.secret.get() == YesNo::Yes { "password" } else { "text" }
+                                }
+                                class="w-full input validator"
+                                minlength="1"
+                                maxlength="50"
+                                required
+                                bind:value=form.var_value
+                            />
+                        }
+                    }
+                >
+                    <DictValueSelect class="w-full" dict_code selected=form.var_value />
+                </Show>
                 <p class="validator-hint">必须输入</p>
                 <label class="label">是否公共</label>
                 <StatusSelect selected=form.public placeholder="是否公共" />
                 <p class="validator-hint">必须输入</p>
+                <label class="label">是否密钥</label>
+                <StatusSelect selected=form.secret placeholder="是否密钥" />
+                <p class="validator-hint">必须输入</p>
+                <label class="label">是否受保护</label>
+                <StatusSelect selected=form.protected placeholder="是否受保护" />
+                <p class="validator-hint">"受保护配置的修改需要审批后生效"</p>
                 <label class="label">是否有效</label>
                 <StatusSelect selected=form.status placeholder="是否有效" />
                 <p class="validator-hint">必须输入</p>
@@ -84,47 +122,70 @@ pub(crate) fn AddConfigForm(
 
 struct AddConfigFormData {
     app: RwSignal<App>,
-    app_version: RwSignal<Option<String>>,
+    app_version: RwSignal<String>,
     var_name: RwSignal<String>,
     var_value: RwSignal<String>,
     public: RwSignal<YesNo>,
+    secret: RwSignal<YesNo>,
+    protected: RwSignal<YesNo>,
+    dict_code: RwSignal<String>,
     remark: RwSignal<String>,
     status: RwSignal<YesNo>,
 }
 impl AddConfigFormData {
-    fn new(clone: Option<ConfigListItem>) -> Self {
+    fn new(clone: Option<ConfigListItemExt>) -> Self {
         if clone.is_none() {
             Self {
                 app: RwSignal::new(App::TowerServer),
-                app_version: RwSignal::new(None),
+                app_version: RwSignal::new("".to_string()),
                 var_name: RwSignal::new("".to_string()),
                 var_value: RwSignal::new("".to_string()),
                 public: RwSignal::new(YesNo::Yes),
+                secret: RwSignal::new(YesNo::No),
+                protected: RwSignal::new(YesNo::No),
+                dict_code: RwSignal::new("".to_string()),
                 remark: RwSignal::new("".to_string()),
                 status: RwSignal::new(YesNo::Yes),
             }
         } else {
             let clone = clone.unwrap();
+            let var_value = if clone.is_secret() {
+                "".to_string()
+            } else {
+                clone.var_value.clone()
+            };
+            let secret = clone.secret;
+            let protected = clone.protected;
+            let dict_code = clone.dict_code.clone();
+            let clone = clone.base;
             Self {
                 app: RwSignal::new(clone.app),
-                app_version: RwSignal::new(clone.app_version),
+                app_version: RwSignal::new(clone.app_version.unwrap_or_default()),
                 var_name: RwSignal::new(clone.var_name),
-                var_value: RwSignal::new(clone.var_value),
+                var_value: RwSignal::new(var_value),
                 public: RwSignal::new(clone.public),
+                secret: RwSignal::new(secret),
+                protected: RwSignal::new(protected),
+                dict_code: RwSignal::new(dict_code.unwrap_or_default()),
                 remark: RwSignal::new(clone.remark.unwrap_or_default()),
                 status: RwSignal::new(clone.status),
             }
         }
     }
-    fn to_req(&self) -> AddConfigReq {
-        AddConfigReq {
-            app_id: self.app.get_untracked().to_string(),
-            app_version: self.app_version.get_untracked(),
-            var_name: self.var_name.get_untracked(),
-            var_value: self.var_value.get_untracked(),
-            public: self.public.get_untracked(),
-            remark: str_to_option(self.remark.get_untracked()),
-            status: self.status.get_untracked(),
+    fn to_req(&self) -> AddConfigReqExt {
+        AddConfigReqExt {
+            base: AddConfigReq {
+                app_id: self.app.get_untracked().to_string(),
+                app_version: str_trim_to_option(self.app_version.get_untracked()),
+                var_name: self.var_name.get_untracked(),
+                var_value: self.var_value.get_untracked(),
+                public: self.public.get_untracked(),
+                remark: str_to_option(self.remark.get_untracked()),
+                status: self.status.get_untracked(),
+            },
+            secret: self.secret.get_untracked(),
+            protected: self.protected.get_untracked(),
+            dict_code: str_trim_to_option(self.dict_code.get_untracked()),
         }
     }
 }
@@ -0,0 +1,197 @@
+use std::collections::HashSet;
+
+use leptos::{prelude::*, task::spawn_local};
+use tower::tauri_web::prelude::*;
+
+use crate::{
+    model::{
+        BatchDeleteConfigReq, BatchUpdateConfigReq, CloneConfigsReq, ConfigListItemExt,
+        APP_VERSION_PATTERN,
+    },
+    page::component::AppSelect,
+    service::{async_batch_delete_configs, async_batch_update_configs, async_clone_configs},
+};
+
+#[component]
+pub(crate) fn BatchConfigBar(
+    selected: RwSignal<HashSet<i32>>,
+    protected_ids: RwSignal<HashSet<i32>>,
+    configs_resource: WebResult<Vec<ConfigListItemExt>>,
+) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    let open_clone = RwSignal::new(false);
+    // 受保护配置需逐项修改并提交审批，选中时禁用批量操作
+    let protected_count = move || {
+        selected.with(|selected| {
+            protected_ids.with_untracked(|protected_ids| {
+                selected
+                    .iter()
+                    .filter(|id| protected_ids.contains(id))
+                    .count()
+            })
+        })
+    };
+    let blocked = move || protected_count() > 0;
+    let batch_update = move |status: Option<YesNo>, public: Option<YesNo>| {
+        let req = BatchUpdateConfigReq {
+            ids: selected.get_untracked().into_iter().collect(),
+            status,
+            public,
+        };
+        spawn_local(async move {
+            let resp = async_batch_update_configs(req).await;
+            tip_or(resp, app_state.op_tip, |_| {
+                configs_resource.refetch();
+                app_state.success("批量修改成功。");
+            });
+        });
+    };
+    view! {
+        <Show when=move || !selected.get().is_empty()>
+            <div class="flex gap-4 items-center">
+                <span>{move || format!("已选 {} 项", selected.get().len())}</span>
+                <Show when=blocked>
+                    <span class="text-warning">
+                        {move || {
+                            format!("其中 {} 项为受保护配置，需逐项修改并提交审批", protected_count())
+                        }}
+                    </span>
+                </Show>
+                <button
+                    class="btn btn-sm"
+                    disabled=blocked
+                    on:click=move |_| batch_update(Some(YesNo::Yes), None)
+                >
+                    启用
+                </button>
+                <button
+                    class="btn btn-sm"
+                    disabled=blocked
+                    on:click=move |_| batch_update(Some(YesNo::No), None)
+                >
+                    禁用
+                </button>
+                <button
+                    class="btn btn-sm"
+                    disabled=blocked
+                    on:click=move |_| batch_update(None, Some(YesNo::Yes))
+                >
+                    设为公共
+                </button>
+                <button
+                    class="btn btn-sm"
+                    disabled=blocked
+                    on:click=move |_| batch_update(None, Some(YesNo::No))
+                >
+                    取消公共
+                </button>
+                <button
+                    class="btn btn-sm"
+                    disabled=blocked
+                    on:click=move |_| {
+                        open_clone.set(true);
+                    }
+                >
+                    克隆到
+                </button>
+                <Show when=move || !blocked()>
+                    <ConfirmDialogButton
+                        btn="删除"
+                        btn_class="btn-sm"
+                        title="批量删除配置"
+                        message=move || format!("确认删除选中的 {} 项配置？", selected.get().len())
+                        on_ok=move || {
+                            let req = BatchDeleteConfigReq {
+                                ids: selected.get_untracked().into_iter().collect(),
+                            };
+                            spawn_local(async move {
+                                match async_batch_delete_configs(req).await {
+                                    Ok(_) => {
+                                        configs_resource.refetch();
+                                        app_state.success("删除成功")
+                                    }
+                                    Err(err) => app_state.error(err.to_string()),
+                                }
+                            });
+                        }
+                    />
+                </Show>
+                <button
+                    class="btn btn-sm btn-ghost"
+                    on:click=move |_| {
+                        selected.set(HashSet::new());
+                    }
+                >
+                    取消选择
+                </button>
+            </div>
+        </Show>
+        <Show when=move || { open_clone.get() }>
+            <Dialog open=open_clone title="克隆配置">
+                <CloneConfigsForm open=open_clone configs_resource selected />
+            </Dialog>
+        </Show>
+    }
+}
+
+#[component]
+fn CloneConfigsForm(
+    open: RwSignal<bool>,
+    configs_resource: WebResult<Vec<ConfigListItemExt>>,
+    selected: RwSignal<HashSet<i32>>,
+) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    let app = RwSignal::new(App::TowerServer);
+    let app_version = RwSignal::new("".to_string());
+    let op_tiper = OpTiper::new();
+    view! {
+        <form on:submit=move |ev| {
+            ev.prevent_default();
+            let req = CloneConfigsReq {
+                ids: selected.get_untracked().into_iter().collect(),
+                app_id: app.get_untracked().to_string(),
+                app_version: str_trim_to_option(app_version.get_untracked()),
+            };
+            spawn_local(async move {
+                let resp = async_clone_configs(req).await;
+                tip_or(
+                    resp,
+                    op_tiper.0,
+                    |resp| {
+                        configs_resource.refetch();
+                        open.set(false);
+                        app_state
+                            .success(
+                                format!(
+                                    "克隆完成，新增 {} 项，跳过已存在 {} 项。",
+                                    resp.created.len(),
+                                    resp.skipped.len(),
+                                ),
+                            );
+                    },
+                );
+            });
+        }>
+            <fieldset class="p-4 w-full fieldset">
+                <label class="label">{move || format!("将选中的 {} 项配置克隆到", selected.get().len())}</label>
+                <label class="label">应用</label>
+                <AppSelect selected=app />
+                <p class="validator-hint">必须输入</p>
+                <label class="label">应用版本</label>
+                <input
+                    type="text"
+                    class="w-full input validator"
+                    maxlength="20"
+                    pattern=APP_VERSION_PATTERN
+                    placeholder="留空对所有版本生效"
+                    bind:value=app_version
+                />
+                <p class="validator-hint">"版本格式：[>=|>|<=|<|=]主版本[.次版本[.修订号]]"</p>
+                <OpTip content=op_tiper.0 />
+                <button class="mt-4 btn btn-neutral" type="submit">
+                    确定
+                </button>
+            </fieldset>
+        </form>
+    }
+}
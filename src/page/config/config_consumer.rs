@@ -0,0 +1,61 @@
+use leptos::prelude::*;
+use tower::tauri_web::prelude::*;
+
+use crate::{model::ConfigListItemExt, service::async_get_config_consumers};
+
+#[component]
+pub(crate) fn ConfigConsumerTable(config: RwSignal<Option<ConfigListItemExt>>) -> impl IntoView {
+    let consumers_resource = LocalResource::new(move || {
+        let id = config.get().map(|config| config.id).unwrap_or_default();
+        async_get_config_consumers(id)
+    });
+    view! {
+        <table class="table">
+            <thead>
+                <tr>
+                    <th></th>
+                    <th>应用</th>
+                    <th>版本</th>
+                    <th>环境</th>
+                    <th>读取次数</th>
+                    <th>最近读取</th>
+                </tr>
+            </thead>
+            <tbody>
+                <Suspense fallback=move || {
+                    view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+                }>
+                    {move || {
+                        consumers_resource
+                            .to_view(|list| {
+                                if list.is_empty() {
+                                    return view! {
+                                        <tr>
+                                            <td colspan="6">暂无客户端读取记录</td>
+                                        </tr>
+                                    }
+                                        .into_any();
+                                }
+                                list.into_iter()
+                                    .enumerate()
+                                    .map(|(index, consumer)| {
+                                        view! {
+                                            <tr>
+                                                <th>{index + 1}</th>
+                                                <td>{consumer.app.label()}</td>
+                                                <td>{consumer.app_version.clone()}</td>
+                                                <td>{consumer.env.clone()}</td>
+                                                <td>{consumer.read_count}</td>
+                                                <td>{from_secs_and_default(consumer.last_read)}</td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view()
+                                    .into_any()
+                            })
+                    }}
+                </Suspense>
+            </tbody>
+        </table>
+    }
+}
@@ -0,0 +1,100 @@
+use leptos::{prelude::*, task::spawn_local};
+use tower::{common::bincode_encode, tauri_web::prelude::*};
+
+use crate::{
+    model::{ConfigListReqExt, ExportConfigReq},
+    service::async_export_configs,
+};
+
+#[component]
+pub(crate) fn ExportConfigForm(#[prop(into)] query: Signal<ConfigListReqExt>) -> impl IntoView {
+    let form = ExportConfigFormData::new();
+    let download = RwSignal::new(None::<String>);
+    let op_tiper = OpTiper::new();
+    view! {
+        <form on:submit=move |ev| {
+            ev.prevent_default();
+            let req = form.to_req(query.get_untracked());
+            spawn_local(async move {
+                let resp = async_export_configs(req).await;
+                tip_or(
+                    resp,
+                    op_tiper.0,
+                    |list| {
+                        let json = serde_json::to_string_pretty(&list).unwrap_or_default();
+                        download
+                            .set(
+                                Some(
+                                    format!(
+                                        "data:application/json;charset=utf-8,{}",
+                                        String::from(js_sys::encode_uri_component(&json)),
+                                    ),
+                                ),
+                            );
+                    },
+                );
+            });
+        }>
+            <fieldset class="p-4 w-full fieldset">
+                <label class="label">
+                    <input
+                        type="checkbox"
+                        class="checkbox"
+                        bind:checked=form.include_secret
+                        on:change=move |_| download.set(None)
+                    />
+                    包含密钥配置值
+                </label>
+                <Show when=move || form.include_secret.get()>
+                    <label class="label">登录密码</label>
+                    <input
+                        type="password"
+                        class="w-full input validator"
+                        minlength="6"
+                        maxlength="16"
+                        required
+                        bind:value=form.password
+                    />
+                    <p class="validator-hint">"导出密钥需要重新验证身份，导出操作将被审计"</p>
+                </Show>
+                <OpTip content=op_tiper.0 />
+                <button class="mt-4 btn btn-neutral" type="submit">
+                    导出
+                </button>
+                {move || {
+                    download
+                        .get()
+                        .map(|href| {
+                            view! {
+                                <a class="mt-2 btn btn-link" href=href download="configs.json">
+                                    "下载 configs.json"
+                                </a>
+                            }
+                        })
+                }}
+            </fieldset>
+        </form>
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ExportConfigFormData {
+    include_secret: RwSignal<bool>,
+    password: RwSignal<String>,
+}
+impl ExportConfigFormData {
+    fn new() -> Self {
+        Self {
+            include_secret: RwSignal::new(false),
+            password: RwSignal::new("".to_string()),
+        }
+    }
+    fn to_req(&self, query: ConfigListReqExt) -> ExportConfigReq {
+        let include_secret = self.include_secret.get_untracked();
+        ExportConfigReq {
+            query,
+            include_secret,
+            password: include_secret.then(|| bincode_encode(self.password.get_untracked())),
+        }
+    }
+}
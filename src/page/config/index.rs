@@ -1,15 +1,15 @@
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 use leptos::{prelude::*, task::spawn_local};
-use tower::{
-    management_model::{ConfigListItem, ConfigListReq},
-    tauri_web::prelude::*,
-    web::component::Checkboxs,
-};
+use leptos_router::components::A;
+use tower::{management_model::ConfigListReq, tauri_web::prelude::*, web::component::Checkboxs};
 
 use crate::{
+    model::{ConfigListItemExt, ConfigListReqExt, ScheduledChangeListReq, STALE_DAYS},
     page::component::AppSelect,
-    service::{async_delete_config, async_get_configs},
+    service::{
+        async_delete_config, async_get_configs, async_get_dict_items, async_get_scheduled_changes,
+    },
 };
 
 use super::*;
@@ -18,11 +18,52 @@ use super::*;
 pub fn ConfigPane() -> impl IntoView {
     let app_state = expect_context::<AppState>();
     let query_form = ConfigFormData::new();
-    let configs_resource = LocalResource::new(move || async_get_configs(query_form.to_req()));
+    let selected = RwSignal::new(HashSet::<i32>::new());
+    let visible_ids = RwSignal::new(Vec::<i32>::new());
+    let protected_ids = RwSignal::new(HashSet::<i32>::new());
+    let configs_resource = LocalResource::new(move || {
+        selected.set(HashSet::new());
+        async_get_configs(query_form.to_req())
+    });
+    let dict_labels_resource = LocalResource::new(move || async move {
+        let dict_codes = configs_resource
+            .await
+            .map(|list| {
+                list.into_iter()
+                    .filter_map(|config| config.dict_code)
+                    .collect::<BTreeSet<_>>()
+            })
+            .unwrap_or_default();
+        let mut labels = HashMap::new();
+        for dict_code in dict_codes {
+            if let Ok(items) = async_get_dict_items(Some(dict_code.clone())).await {
+                for item in items {
+                    labels.insert(
+                        (dict_code.clone(), item.item_value.clone()),
+                        item.item_name.clone(),
+                    );
+                }
+            }
+        }
+        labels
+    });
     let current_config = RwSignal::new(None);
     let current_clone = RwSignal::new(None);
     let open_add = RwSignal::new(false);
     let open_update = RwSignal::new(false);
+    let current_reveal = RwSignal::new(None);
+    let open_reveal = RwSignal::new(false);
+    let open_export = RwSignal::new(false);
+    let open_resolve = RwSignal::new(false);
+    let schedules_resource = LocalResource::new(move || {
+        async_get_scheduled_changes(ScheduledChangeListReq {
+            app_id: query_form.app.get().to_string(),
+        })
+    });
+    let current_schedule = RwSignal::new(None);
+    let open_schedule = RwSignal::new(false);
+    let current_consumer = RwSignal::new(None);
+    let open_consumer = RwSignal::new(false);
     view! {
         <div class="flex gap-4">
             <AppSelect selected=query_form.app />
@@ -35,12 +76,25 @@ pub fn ConfigPane() -> impl IntoView {
                     bind:value=query_form.var_name
                 />
             </label>
+            <label class="floating-label">
+                <span>版本</span>
+                <input
+                    type="text"
+                    placeholder="应用版本"
+                    class="input input-md"
+                    bind:value=query_form.app_version
+                />
+            </label>
             <Checkboxs
                 name="config_status"
                 values=YesNo::all()
                 checked=query_form.status
                 multi=false
             />
+            <label class="label">
+                <input type="checkbox" class="checkbox" bind:checked=query_form.stale_only />
+                {format!("仅看{}天未读取", STALE_DAYS)}
+            </label>
             <button
                 class="btn"
                 on:click=move |_| {
@@ -66,19 +120,65 @@ pub fn ConfigPane() -> impl IntoView {
             >
                 新增
             </button>
+            <button
+                class="btn"
+                on:click=move |_| {
+                    open_export.set(true);
+                }
+            >
+                导出
+            </button>
+            <button
+                class="btn"
+                on:click=move |_| {
+                    open_resolve.set(true);
+                }
+            >
+                版本预览
+            </button>
+            <A href="/config/search" attr:class="btn">
+                全局搜索
+            </A>
+            <A href="/config/template" attr:class="btn">
+                配置模板
+            </A>
         </div>
+        <BatchConfigBar selected protected_ids configs_resource />
         <table class="table">
             <thead>
                 <tr>
+                    <th>
+                        <input
+                            type="checkbox"
+                            class="checkbox checkbox-sm"
+                            prop:checked=move || {
+                                let selected = selected.get();
+                                let visible_ids = visible_ids.get();
+                                !visible_ids.is_empty()
+                                    && visible_ids.iter().all(|id| selected.contains(id))
+                            }
+                            on:change:target=move |ev| {
+                                if ev.target().checked() {
+                                    selected.set(visible_ids.get_untracked().into_iter().collect());
+                                } else {
+                                    selected.set(HashSet::new());
+                                }
+                            }
+                        />
+                    </th>
                     <th></th>
                     <th>应用</th>
+                    <th>版本</th>
                     <th>名称</th>
                     <th>值</th>
                     <th>是否公开</th>
+                    <th>是否密钥</th>
+                    <th>是否受保护</th>
                     <th>备注</th>
                     <th>状态</th>
                     <th>创建时间</th>
                     <th>更新时间</th>
+                    <th>最近读取</th>
                     <th>操作</th>
                 </tr>
             </thead>
@@ -89,17 +189,81 @@ pub fn ConfigPane() -> impl IntoView {
                     {move || {
                         configs_resource
                             .to_view(|list| {
+                                // 受保护配置按整页统计，筛选隐藏的行也要拦住批量操作
+                                protected_ids
+                                    .update_untracked(|ids| {
+                                        *ids = list
+                                            .iter()
+                                            .filter(|config| config.is_protected())
+                                            .map(|config| config.id)
+                                            .collect();
+                                    });
+                                let now_secs = (js_sys::Date::now() / 1000.0) as i64;
+                                let list = if query_form.stale_only.get() {
+                                    list.into_iter()
+                                        .filter(|config| config.is_stale(now_secs))
+                                        .collect()
+                                } else {
+                                    list
+                                };
+                                let ids: Vec<i32> = list.iter().map(|config| config.id).collect();
+                                // 切换筛选后只保留仍可见的勾选项，批量操作不作用于隐藏的行
+                                if selected
+                                    .with_untracked(|selected| {
+                                        selected.iter().any(|id| !ids.contains(id))
+                                    })
+                                {
+                                    selected.update(|selected| selected.retain(|id| ids.contains(id)));
+                                }
+                                visible_ids.update_untracked(|visible_ids| *visible_ids = ids);
                                 list.into_iter()
                                     .enumerate()
                                     .map(|(index, config)| {
                                         let config = config.clone();
+                                        let id = config.id;
                                         view! {
                                             <tr>
+                                                <th>
+                                                    <input
+                                                        type="checkbox"
+                                                        class="checkbox checkbox-sm"
+                                                        prop:checked=move || selected.get().contains(&id)
+                                                        on:change:target=move |ev| {
+                                                            let checked = ev.target().checked();
+                                                            selected
+                                                                .update(|selected| {
+                                                                    if checked {
+                                                                        selected.insert(id);
+                                                                    } else {
+                                                                        selected.remove(&id);
+                                                                    }
+                                                                });
+                                                        }
+                                                    />
+                                                </th>
                                                 <th>{index + 1}</th>
                                                 <td>{config.app.label()}</td>
+                                                <td>{config.app_version.clone()}</td>
                                                 <td>{config.var_name.clone()}</td>
-                                                <td>{config.var_value.clone()}</td>
+                                                <td>
+                                                    {match config.dict_code.clone() {
+                                                        Some(dict_code) if !config.is_secret() => {
+                                                            let var_value = config.var_value.clone();
+                                                            view! {
+                                                                <DictValueLabel
+                                                                    dict_labels_resource
+                                                                    dict_code
+                                                                    var_value
+                                                                />
+                                                            }
+                                                                .into_any()
+                                                        }
+                                                        _ => config.masked_value().into_any(),
+                                                    }}
+                                                </td>
                                                 <td>{config.public.label()}</td>
+                                                <td>{config.secret.label()}</td>
+                                                <td>{config.protected.label()}</td>
                                                 <td>{config.remark.clone()}</td>
                                                 <td>{config.status.label()}</td>
                                                 <td>{from_secs_and_default(config.created)}</td>
@@ -111,27 +275,74 @@ pub fn ConfigPane() -> impl IntoView {
                                                             |updated| { from_secs_and_default(updated) },
                                                         )}
                                                 </td>
+                                                <td>
+                                                    {config.last_read.map(from_secs_and_default)}
+                                                    {config
+                                                        .is_stale(now_secs)
+                                                        .then(|| {
+                                                            view! {
+                                                                <span class="badge badge-xs badge-warning">待清理</span>
+                                                            }
+                                                        })}
+                                                </td>
                                                 <td class="flex gap-4">
                                                     <CloneButton current_clone open_add config=config.clone() />
+                                                    {config
+                                                        .is_secret()
+                                                        .then(|| {
+                                                            view! {
+                                                                <RevealButton
+                                                                    current_reveal
+                                                                    open_reveal
+                                                                    config=config.clone()
+                                                                />
+                                                            }
+                                                        })}
                                                     <UpdateButton
                                                         current_config
                                                         open_update
                                                         config=config.clone()
                                                     />
-                                                    <ConfirmDialogButton
-                                                        btn="删除"
-                                                        btn_class="btn-xs"
-                                                        title="删除配置"
-                                                        message="确认删除？"
-                                                        on_ok=move || {
-                                                            spawn_local(async move {
-                                                                match async_delete_config(config.id).await {
-                                                                    Ok(_) => app_state.success("删除成功"),
-                                                                    Err(err) => app_state.error(err.to_string()),
+                                                    <ScheduleButton
+                                                        current_schedule
+                                                        open_schedule
+                                                        config=config.clone()
+                                                    />
+                                                    <ConsumerButton
+                                                        current_consumer
+                                                        open_consumer
+                                                        config=config.clone()
+                                                    />
+                                                    {if config.is_protected() {
+                                                        view! {
+                                                            <button
+                                                                class="btn btn-xs"
+                                                                disabled
+                                                                title="受保护配置不能直接删除"
+                                                            >
+                                                                删除
+                                                            </button>
+                                                        }
+                                                            .into_any()
+                                                    } else {
+                                                        view! {
+                                                            <ConfirmDialogButton
+                                                                btn="删除"
+                                                                btn_class="btn-xs"
+                                                                title="删除配置"
+                                                                message="确认删除？"
+                                                                on_ok=move || {
+                                                                    spawn_local(async move {
+                                                                        match async_delete_config(config.id).await {
+                                                                            Ok(_) => app_state.success("删除成功"),
+                                                                            Err(err) => app_state.error(err.to_string()),
+                                                                        }
+                                                                    });
                                                                 }
-                                                            });
+                                                            />
                                                         }
-                                                    />
+                                                            .into_any()
+                                                    }}
                                                 </td>
                                             </tr>
                                         }
@@ -143,6 +354,7 @@ pub fn ConfigPane() -> impl IntoView {
                 </Suspense>
             </tbody>
         </table>
+        <ScheduledChangeList schedules_resource open_schedule current_schedule />
         <Show when=move || { open_add.get() }>
             <Dialog open=open_add title="新增配置">
                 <AddConfigForm open=open_add configs_resource current_clone />
@@ -153,14 +365,39 @@ pub fn ConfigPane() -> impl IntoView {
                 <UpdateConfigForm open=open_update configs_resource config=current_config />
             </Dialog>
         </Show>
+        <Show when=move || { current_reveal.get().is_some() && open_reveal.get() }>
+            <Dialog open=open_reveal title="查看密钥">
+                <RevealConfigForm config=current_reveal />
+            </Dialog>
+        </Show>
+        <Show when=move || { open_export.get() }>
+            <Dialog open=open_export title="导出配置">
+                <ExportConfigForm query=Signal::derive(move || query_form.to_req()) />
+            </Dialog>
+        </Show>
+        <Show when=move || { current_schedule.get().is_some() && open_schedule.get() }>
+            <Dialog open=open_schedule title="定时变更">
+                <ScheduleConfigForm open=open_schedule schedules_resource target=current_schedule />
+            </Dialog>
+        </Show>
+        <Show when=move || { current_consumer.get().is_some() && open_consumer.get() }>
+            <Dialog open=open_consumer title="读取方">
+                <ConfigConsumerTable config=current_consumer />
+            </Dialog>
+        </Show>
+        <Show when=move || { open_resolve.get() }>
+            <Dialog open=open_resolve title="版本预览">
+                <ResolveConfigPane />
+            </Dialog>
+        </Show>
     }
 }
 
 #[component]
 pub fn CloneButton(
-    #[prop(into)] config: Signal<ConfigListItem>,
+    #[prop(into)] config: Signal<ConfigListItemExt>,
     open_add: RwSignal<bool>,
-    current_clone: RwSignal<Option<ConfigListItem>>,
+    current_clone: RwSignal<Option<ConfigListItemExt>>,
 ) -> impl IntoView {
     view! {
         <button
@@ -177,9 +414,9 @@ pub fn CloneButton(
 
 #[component]
 pub fn UpdateButton(
-    #[prop(into)] config: Signal<ConfigListItem>,
+    #[prop(into)] config: Signal<ConfigListItemExt>,
     open_update: RwSignal<bool>,
-    current_config: RwSignal<Option<ConfigListItem>>,
+    current_config: RwSignal<Option<ConfigListItemExt>>,
 ) -> impl IntoView {
     view! {
         <button
@@ -194,11 +431,99 @@ pub fn UpdateButton(
     }
 }
 
+#[component]
+pub fn RevealButton(
+    #[prop(into)] config: Signal<ConfigListItemExt>,
+    open_reveal: RwSignal<bool>,
+    current_reveal: RwSignal<Option<ConfigListItemExt>>,
+) -> impl IntoView {
+    view! {
+        <button
+            class="btn btn-xs"
+            on:click=move |_| {
+                current_reveal.set(Some(config.get_untracked()));
+                open_reveal.set(true)
+            }
+        >
+            查看
+        </button>
+    }
+}
+
+#[component]
+pub fn ScheduleButton(
+    #[prop(into)] config: Signal<ConfigListItemExt>,
+    open_schedule: RwSignal<bool>,
+    current_schedule: RwSignal<Option<ScheduleTarget>>,
+) -> impl IntoView {
+    // 受保护配置的修改需要审批，不能定时变更
+    let protected = config.get_untracked().is_protected();
+    view! {
+        <button
+            class="btn btn-xs"
+            disabled=protected
+            title=protected.then_some("受保护配置的修改需要审批，不能定时变更")
+            on:click=move |_| {
+                current_schedule.set(Some(ScheduleTarget::from_config(&config.get_untracked())));
+                open_schedule.set(true)
+            }
+        >
+            定时
+        </button>
+    }
+}
+
+#[component]
+pub fn ConsumerButton(
+    #[prop(into)] config: Signal<ConfigListItemExt>,
+    open_consumer: RwSignal<bool>,
+    current_consumer: RwSignal<Option<ConfigListItemExt>>,
+) -> impl IntoView {
+    view! {
+        <button
+            class="btn btn-xs"
+            on:click=move |_| {
+                current_consumer.set(Some(config.get_untracked()));
+                open_consumer.set(true)
+            }
+        >
+            读取方
+        </button>
+    }
+}
+
+/// 引用字典的配置值显示为字典项名称，原始值作为提示
+#[component]
+fn DictValueLabel(
+    dict_labels_resource: LocalResource<HashMap<(String, String), String>>,
+    dict_code: String,
+    var_value: String,
+) -> impl IntoView {
+    move || {
+        let dict_code = dict_code.clone();
+        let var_value = var_value.clone();
+        Suspend::new(async move {
+            let labels = dict_labels_resource.await;
+            let label = labels
+                .get(&(dict_code, var_value.clone()))
+                .cloned()
+                .unwrap_or(format!("{}（无效）", var_value));
+            view! {
+                <span class="tooltip" data-tip=var_value>
+                    {label}
+                </span>
+            }
+        })
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 struct ConfigFormData {
     app: RwSignal<App>,
     var_name: RwSignal<String>,
+    app_version: RwSignal<String>,
     status: RwSignal<HashSet<YesNo>>,
+    stale_only: RwSignal<bool>,
 }
 
 impl ConfigFormData {
@@ -206,19 +531,26 @@ impl ConfigFormData {
         Self {
             app: RwSignal::new(App::TowerServer),
             var_name: RwSignal::new("".to_string()),
+            app_version: RwSignal::new("".to_string()),
             status: RwSignal::new(HashSet::new()),
+            stale_only: RwSignal::new(false),
         }
     }
     pub fn reset(&self) {
         self.app.set(App::TowerServer);
         self.var_name.set("".to_string());
+        self.app_version.set("".to_string());
         self.status.set(HashSet::new());
+        self.stale_only.set(false);
     }
-    pub fn to_req(&self) -> ConfigListReq {
-        ConfigListReq {
-            app_id: self.app.get_untracked().label(),
-            var_name: self.var_name.get_untracked(),
-            status: self.status.get_untracked().iter().next().copied(),
+    pub fn to_req(&self) -> ConfigListReqExt {
+        ConfigListReqExt {
+            base: ConfigListReq {
+                app_id: self.app.get_untracked().label(),
+                var_name: self.var_name.get_untracked(),
+                status: self.status.get_untracked().iter().next().copied(),
+            },
+            app_version: str_trim_to_option(self.app_version.get_untracked()),
         }
     }
 }
@@ -6,3 +6,24 @@ pub use add_config::*;
 
 mod update_config;
 pub use update_config::*;
+
+mod reveal_config;
+pub use reveal_config::*;
+
+mod export_config;
+pub use export_config::*;
+
+mod resolve_config;
+pub use resolve_config::*;
+
+mod schedule_config;
+pub use schedule_config::*;
+
+mod search_config;
+pub use search_config::*;
+
+mod batch_config;
+pub use batch_config::*;
+
+mod config_consumer;
+pub use config_consumer::*;
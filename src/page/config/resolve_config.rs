@@ -0,0 +1,112 @@
+use leptos::prelude::*;
+use tower::tauri_web::prelude::*;
+
+use crate::{
+    model::{ResolveConfigReq, SECRET_MASK},
+    page::component::AppSelect,
+    service::async_resolve_configs,
+};
+
+#[component]
+pub(crate) fn ResolveConfigPane() -> impl IntoView {
+    let form = ResolveConfigFormData::new();
+    let query = RwSignal::new(None::<ResolveConfigReq>);
+    let resolved_resource = LocalResource::new(move || {
+        let req = query.get();
+        async move {
+            match req {
+                Some(req) => async_resolve_configs(req).await,
+                None => Ok(vec![]),
+            }
+        }
+    });
+    view! {
+        <form on:submit=move |ev| {
+            ev.prevent_default();
+            query.set(Some(form.to_req()));
+        }>
+            <div class="flex gap-4 p-4">
+                <AppSelect selected=form.app />
+                <label class="floating-label">
+                    <span>客户端版本</span>
+                    <input
+                        type="text"
+                        placeholder="客户端版本，如 1.3.2"
+                        class="input input-md validator"
+                        pattern="\\d+(\\.\\d+){0,2}"
+                        required
+                        bind:value=form.app_version
+                    />
+                </label>
+                <button class="btn" type="submit">
+                    预览
+                </button>
+            </div>
+        </form>
+        <table class="table">
+            <thead>
+                <tr>
+                    <th></th>
+                    <th>名称</th>
+                    <th>生效值</th>
+                    <th>命中版本</th>
+                </tr>
+            </thead>
+            <tbody>
+                <Suspense fallback=move || {
+                    view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+                }>
+                    {move || {
+                        resolved_resource
+                            .to_view(|list| {
+                                list.into_iter()
+                                    .enumerate()
+                                    .map(|(index, config)| {
+                                        let var_value = if config.secret == YesNo::Yes {
+                                            SECRET_MASK.to_string()
+                                        } else {
+                                            config.var_value.clone()
+                                        };
+                                        view! {
+                                            <tr>
+                                                <th>{index + 1}</th>
+                                                <td>{config.var_name.clone()}</td>
+                                                <td>{var_value}</td>
+                                                <td>
+                                                    {config
+                                                        .app_version
+                                                        .clone()
+                                                        .unwrap_or("默认".to_string())}
+                                                </td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view()
+                                    .into_any()
+                            })
+                    }}
+                </Suspense>
+            </tbody>
+        </table>
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ResolveConfigFormData {
+    app: RwSignal<App>,
+    app_version: RwSignal<String>,
+}
+impl ResolveConfigFormData {
+    fn new() -> Self {
+        Self {
+            app: RwSignal::new(App::TowerServer),
+            app_version: RwSignal::new("".to_string()),
+        }
+    }
+    fn to_req(&self) -> ResolveConfigReq {
+        ResolveConfigReq {
+            app_id: self.app.get_untracked().to_string(),
+            app_version: self.app_version.get_untracked().trim().to_string(),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use leptos::{prelude::*, task::spawn_local};
+use tower::{common::bincode_encode, tauri_web::prelude::*};
+
+use crate::{
+    model::{ConfigListItemExt, RevealConfigReq},
+    service::async_reveal_config,
+};
+
+#[component]
+pub(crate) fn RevealConfigForm(config: RwSignal<Option<ConfigListItemExt>>) -> impl IntoView {
+    let config = config.get_untracked().unwrap();
+    let id = config.id;
+    let password = RwSignal::new("".to_string());
+    let revealed = RwSignal::new(None::<String>);
+    let op_tiper = OpTiper::new();
+    view! {
+        <form on:submit=move |ev| {
+            ev.prevent_default();
+            let req = RevealConfigReq {
+                id,
+                password: bincode_encode(password.get_untracked()),
+            };
+            spawn_local(async move {
+                let resp = async_reveal_config(req).await;
+                tip_or(
+                    resp,
+                    op_tiper.0,
+                    |resp| {
+                        password.set("".to_string());
+                        revealed.set(Some(resp.var_value));
+                    },
+                );
+            });
+        }>
+            <fieldset class="p-4 w-full fieldset">
+                <label class="label">配置名称</label>
+                <input type="text" class="w-full input" disabled prop:value=config.var_name.clone() />
+                <Show
+                    when=move || revealed.get().is_some()
+                    fallback=move || {
+                        view! {
+                            <label class="label">登录密码</label>
+                            <input
+                                type="password"
+                                class="w-full input validator"
+                                minlength="6"
+                                maxlength="16"
+                                required
+                                bind:value=password
+                            />
+                            <p class="validator-hint">"查看密钥需要重新验证身份，查看操作将被审计"</p>
+                        }
+                    }
+                >
+                    <label class="label">配置值</label>
+                    <input
+                        type="text"
+                        class="w-full input"
+                        readonly
+                        prop:value=move || revealed.get().unwrap_or_default()
+                    />
+                </Show>
+                <OpTip content=op_tiper.0 />
+                <Show when=move || revealed.get().is_none()>
+                    <button class="mt-4 btn btn-neutral" type="submit">
+                        确定
+                    </button>
+                </Show>
+            </fieldset>
+        </form>
+    }
+}
@@ -0,0 +1,310 @@
+use leptos::{prelude::*, task::spawn_local};
+use tower::{
+    tauri_web::prelude::*,
+    web_model::{OpTipData, OpTipType},
+};
+
+use crate::{
+    model::{ConfigListItemExt, SaveScheduledChangeReq, ScheduledChangeListItem, SECRET_MASK},
+    page::{component::DictValueSelect, datetime_local_to_secs, secs_to_datetime_local},
+    service::{async_cancel_scheduled_change, async_save_scheduled_change},
+};
+
+/// 定时变更的编辑对象：新建时`change`为空
+#[derive(Debug, Clone)]
+pub struct ScheduleTarget {
+    pub config_id: i32,
+    pub var_name: String,
+    pub secret: bool,
+    /// 配置值限定为该字典的字典项
+    pub dict_code: Option<String>,
+    pub change: Option<ScheduledChangeListItem>,
+}
+impl ScheduleTarget {
+    pub fn from_config(config: &ConfigListItemExt) -> Self {
+        Self {
+            config_id: config.id,
+            var_name: config.var_name.clone(),
+            secret: config.is_secret(),
+            dict_code: config.dict_code.clone().filter(|_| !config.is_secret()),
+            change: None,
+        }
+    }
+    pub fn from_change(change: ScheduledChangeListItem) -> Self {
+        Self {
+            config_id: change.config_id,
+            var_name: change.var_name.clone(),
+            secret: change.secret == YesNo::Yes,
+            dict_code: change
+                .dict_code
+                .clone()
+                .filter(|_| change.secret == YesNo::No),
+            change: Some(change),
+        }
+    }
+}
+
+#[component]
+pub(crate) fn ScheduleConfigForm(
+    open: RwSignal<bool>,
+    schedules_resource: WebResult<Vec<ScheduledChangeListItem>>,
+    target: RwSignal<Option<ScheduleTarget>>,
+) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    let target = target.get_untracked().unwrap();
+    let value_type = if target.secret { "password" } else { "text" };
+    let var_name = target.var_name.clone();
+    let dict_code = Signal::from(target.dict_code.clone());
+    let form = ScheduleConfigFormData::new(target);
+    let op_tiper = OpTiper::new();
+    view! {
+        <form on:submit=move |ev| {
+            ev.prevent_default();
+            let req = match form.to_req() {
+                Ok(req) => req,
+                Err(content) => {
+                    op_tiper
+                        .0
+                        .set(
+                            Some(OpTipData {
+                                typ: OpTipType::Warning,
+                                class: "".into(),
+                                content,
+                            }),
+                        );
+                    return;
+                }
+            };
+            spawn_local(async move {
+                let resp = async_save_scheduled_change(req).await;
+                tip_or(
+                    resp,
+                    op_tiper.0,
+                    |_| {
+                        schedules_resource.refetch();
+                        open.set(false);
+                        app_state.success("保存定时变更成功。");
+                    },
+                );
+            });
+        }>
+            <fieldset class="p-4 w-full fieldset">
+                <label class="label">配置名称</label>
+                <input type="text" class="w-full input" disabled prop:value=var_name />
+                <label class="label">生效时间</label>
+                <input
+                    type="datetime-local"
+                    class="w-full input validator"
+                    required
+                    bind:value=form.fire_at
+                />
+                <p class="validator-hint">必须输入</p>
+                <label class="label">
+                    <input type="checkbox" class="checkbox" bind:checked=form.change_value />
+                    修改配置值
+                </label>
+                <Show when=move || form.change_value.get()>
+                    <Show
+                        when=move || dict_code.get().is_some()
+                        fallback=move || {
+                            view! {
+                                <input
+                                    type=value_type
+                                    class="w-full input validator"
+                                    minlength="1"
+                                    maxlength="50"
+                                    required
+                                    bind:value=form.var_value
+                                />
+                            }
+                        }
+                    >
+                        <DictValueSelect class="w-full" dict_code selected=form.var_value />
+                    </Show>
+                    <p class="validator-hint">必须输入</p>
+                </Show>
+                <label class="label">修改状态</label>
+                <select
+                    class="w-full select"
+                    on:change:target=move |ev| {
+                        form.status.set(YesNo::try_from(ev.target().value()).ok());
+                    }
+                    prop:value=move || form.status.get().map(|status| status.to_string()).unwrap_or_default()
+                >
+                    <option value="">不修改</option>
+                    {YesNo::all()
+                        .into_iter()
+                        .map(|status| {
+                            view! { <option value=status.to_string()>{status.label()}</option> }
+                        })
+                        .collect_view()}
+                </select>
+                <OpTip content=op_tiper.0 />
+                <button class="mt-4 btn btn-neutral" type="submit">
+                    确定
+                </button>
+            </fieldset>
+        </form>
+    }
+}
+
+#[component]
+pub(crate) fn ScheduledChangeList(
+    schedules_resource: WebResult<Vec<ScheduledChangeListItem>>,
+    open_schedule: RwSignal<bool>,
+    current_schedule: RwSignal<Option<ScheduleTarget>>,
+) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    view! {
+        <div class="flex items-center">
+            <label class="menu-title">待生效变更</label>
+            <button
+                class="btn btn-xs"
+                on:click=move |_| {
+                    schedules_resource.refetch();
+                }
+            >
+                刷新
+            </button>
+        </div>
+        <table class="table">
+            <thead>
+                <tr>
+                    <th></th>
+                    <th>应用</th>
+                    <th>名称</th>
+                    <th>新值</th>
+                    <th>新状态</th>
+                    <th>生效时间</th>
+                    <th>创建人</th>
+                    <th>操作</th>
+                </tr>
+            </thead>
+            <tbody>
+                <Suspense fallback=move || {
+                    view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+                }>
+                    {move || {
+                        schedules_resource
+                            .to_view(move |list| {
+                                list.into_iter()
+                                    .enumerate()
+                                    .map(|(index, change)| {
+                                        let secret = change.secret == YesNo::Yes;
+                                        let change_id = change.id;
+                                        let var_value = change
+                                            .var_value
+                                            .clone()
+                                            .map(|value| {
+                                                if secret { SECRET_MASK.to_string() } else { value }
+                                            });
+                                        view! {
+                                            <tr>
+                                                <th>{index + 1}</th>
+                                                <td>{change.app.label()}</td>
+                                                <td>{change.var_name.clone()}</td>
+                                                <td>{var_value}</td>
+                                                <td>{change.status.map(|status| status.label())}</td>
+                                                <td>{from_secs_and_default(change.fire_at)}</td>
+                                                <td>{change.creator.clone()}</td>
+                                                <td class="flex gap-4">
+                                                    <button
+                                                        class="btn btn-xs"
+                                                        on:click=move |_| {
+                                                            current_schedule
+                                                                .set(Some(ScheduleTarget::from_change(change.clone())));
+                                                            open_schedule.set(true)
+                                                        }
+                                                    >
+                                                        修改
+                                                    </button>
+                                                    <ConfirmDialogButton
+                                                        btn="取消"
+                                                        btn_class="btn-xs"
+                                                        title="取消定时变更"
+                                                        message="确认取消？"
+                                                        on_ok=move || {
+                                                            spawn_local(async move {
+                                                                match async_cancel_scheduled_change(change_id).await {
+                                                                    Ok(_) => {
+                                                                        schedules_resource.refetch();
+                                                                        app_state.success("取消成功")
+                                                                    }
+                                                                    Err(err) => app_state.error(err.to_string()),
+                                                                }
+                                                            });
+                                                        }
+                                                    />
+                                                </td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view()
+                                    .into_any()
+                            })
+                    }}
+                </Suspense>
+            </tbody>
+        </table>
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ScheduleConfigFormData {
+    id: Option<i32>,
+    config_id: i32,
+    fire_at: RwSignal<String>,
+    change_value: RwSignal<bool>,
+    var_value: RwSignal<String>,
+    status: RwSignal<Option<YesNo>>,
+}
+impl ScheduleConfigFormData {
+    fn new(target: ScheduleTarget) -> Self {
+        let change = target.change;
+        Self {
+            id: change.as_ref().map(|change| change.id),
+            config_id: target.config_id,
+            fire_at: RwSignal::new(
+                change
+                    .as_ref()
+                    .map(|change| secs_to_datetime_local(change.fire_at))
+                    .unwrap_or_default(),
+            ),
+            change_value: RwSignal::new(
+                change
+                    .as_ref()
+                    .is_some_and(|change| change.var_value.is_some()),
+            ),
+            var_value: RwSignal::new(
+                change
+                    .as_ref()
+                    .filter(|_| !target.secret)
+                    .and_then(|change| change.var_value.clone())
+                    .unwrap_or_default(),
+            ),
+            status: RwSignal::new(change.and_then(|change| change.status)),
+        }
+    }
+    fn to_req(&self) -> Result<SaveScheduledChangeReq, String> {
+        let fire_at =
+            datetime_local_to_secs(&self.fire_at.get_untracked()).ok_or("生效时间格式错误")?;
+        if fire_at * 1000 <= js_sys::Date::now() as i64 {
+            return Err("生效时间必须晚于当前时间".to_string());
+        }
+        let var_value = self
+            .change_value
+            .get_untracked()
+            .then(|| self.var_value.get_untracked());
+        let status = self.status.get_untracked();
+        if var_value.is_none() && status.is_none() {
+            return Err("请至少修改配置值或状态中的一项".to_string());
+        }
+        Ok(SaveScheduledChangeReq {
+            id: self.id,
+            config_id: self.config_id,
+            var_value,
+            status,
+            fire_at,
+        })
+    }
+}
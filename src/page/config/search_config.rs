@@ -0,0 +1,219 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use leptos::prelude::*;
+use tower::{tauri_web::prelude::*, web::component::Checkboxs};
+
+use crate::{
+    model::{ConfigListItemExt, ConfigSearchReq},
+    service::async_search_configs,
+};
+
+#[component]
+pub fn ConfigSearchPane() -> impl IntoView {
+    let query_form = ConfigSearchFormData::new();
+    let query = RwSignal::new(None::<ConfigSearchReq>);
+    let configs_resource = LocalResource::new(move || {
+        let req = query.get();
+        async move {
+            match req {
+                Some(req) => async_search_configs(req).await,
+                None => Ok(vec![]),
+            }
+        }
+    });
+    let compare = RwSignal::new(false);
+    view! {
+        <div class="flex gap-4">
+            <label class="floating-label">
+                <span>名称</span>
+                <input
+                    type="text"
+                    placeholder="配置名称"
+                    class="input input-md"
+                    bind:value=query_form.var_name
+                />
+            </label>
+            <label class="floating-label">
+                <span>值</span>
+                <input
+                    type="text"
+                    placeholder="配置值包含，不匹配密钥配置"
+                    class="input input-md"
+                    bind:value=query_form.var_value
+                />
+            </label>
+            <label class="floating-label">
+                <span>备注</span>
+                <input
+                    type="text"
+                    placeholder="备注包含"
+                    class="input input-md"
+                    bind:value=query_form.remark
+                />
+            </label>
+            <Checkboxs
+                name="search_config_status"
+                values=YesNo::all()
+                checked=query_form.status
+                multi=false
+            />
+            <button
+                class="btn"
+                on:click=move |_| {
+                    query.set(Some(query_form.to_req()));
+                }
+            >
+                查询
+            </button>
+            <button
+                class="btn"
+                on:click=move |_| {
+                    query_form.reset();
+                }
+            >
+                重置
+            </button>
+            <label class="label">
+                <input type="checkbox" class="toggle" bind:checked=compare />
+                对比视图
+            </label>
+        </div>
+        <Suspense fallback=move || {
+            view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+        }>
+            {move || {
+                configs_resource
+                    .to_view(move |list| {
+                        if compare.get() {
+                            view! { <ConfigCompareGrid configs=list /> }.into_any()
+                        } else {
+                            view! { <ConfigSearchTable configs=list /> }.into_any()
+                        }
+                    })
+            }}
+        </Suspense>
+    }
+}
+
+#[component]
+fn ConfigSearchTable(configs: Vec<ConfigListItemExt>) -> impl IntoView {
+    view! {
+        <table class="table">
+            <thead>
+                <tr>
+                    <th></th>
+                    <th>应用</th>
+                    <th>版本</th>
+                    <th>名称</th>
+                    <th>值</th>
+                    <th>备注</th>
+                    <th>状态</th>
+                    <th>更新时间</th>
+                </tr>
+            </thead>
+            <tbody>
+                {configs
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, config)| {
+                        view! {
+                            <tr>
+                                <th>{index + 1}</th>
+                                <td>{config.app.label()}</td>
+                                <td>{config.app_version.clone()}</td>
+                                <td>{config.var_name.clone()}</td>
+                                <td>{config.masked_value()}</td>
+                                <td>{config.remark.clone()}</td>
+                                <td>{config.status.label()}</td>
+                                <td>{from_secs_and_default(config.updated.unwrap_or(config.created))}</td>
+                            </tr>
+                        }
+                    })
+                    .collect_view()}
+            </tbody>
+        </table>
+    }
+}
+
+/// 同名配置按"应用@版本"横向对比，取值不一致的行高亮
+#[component]
+fn ConfigCompareGrid(configs: Vec<ConfigListItemExt>) -> impl IntoView {
+    let mut columns = BTreeSet::new();
+    let mut rows = BTreeMap::<String, BTreeMap<String, String>>::new();
+    for config in configs.iter() {
+        let column = match &config.app_version {
+            Some(app_version) => format!("{}@{}", config.app.label(), app_version),
+            None => config.app.label(),
+        };
+        columns.insert(column.clone());
+        rows.entry(config.var_name.clone())
+            .or_default()
+            .insert(column, config.masked_value());
+    }
+    let columns = columns.into_iter().collect::<Vec<_>>();
+    view! {
+        <table class="table table-pin-rows table-pin-cols">
+            <thead>
+                <tr>
+                    <th>名称</th>
+                    {columns
+                        .iter()
+                        .map(|column| view! { <th>{column.clone()}</th> })
+                        .collect_view()}
+                </tr>
+            </thead>
+            <tbody>
+                {rows
+                    .into_iter()
+                    .map(|(var_name, values)| {
+                        let drift = values.values().collect::<HashSet<_>>().len() > 1;
+                        let row_class = if drift { "bg-orange-400" } else { "" };
+                        view! {
+                            <tr class=row_class>
+                                <th>{var_name}</th>
+                                {columns
+                                    .iter()
+                                    .map(|column| {
+                                        view! { <td>{values.get(column).cloned().unwrap_or("-".to_string())}</td> }
+                                    })
+                                    .collect_view()}
+                            </tr>
+                        }
+                    })
+                    .collect_view()}
+            </tbody>
+        </table>
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ConfigSearchFormData {
+    var_name: RwSignal<String>,
+    var_value: RwSignal<String>,
+    remark: RwSignal<String>,
+    status: RwSignal<HashSet<YesNo>>,
+}
+impl ConfigSearchFormData {
+    fn new() -> Self {
+        Self {
+            var_name: RwSignal::new("".to_string()),
+            var_value: RwSignal::new("".to_string()),
+            remark: RwSignal::new("".to_string()),
+            status: RwSignal::new(HashSet::new()),
+        }
+    }
+    fn reset(&self) {
+        self.var_name.set("".to_string());
+        self.var_value.set("".to_string());
+        self.remark.set("".to_string());
+        self.status.set(HashSet::new());
+    }
+    fn to_req(&self) -> ConfigSearchReq {
+        ConfigSearchReq {
+            var_name: str_trim_to_option(self.var_name.get_untracked()),
+            var_value: str_trim_to_option(self.var_value.get_untracked()),
+            remark: str_trim_to_option(self.remark.get_untracked()),
+            status: self.status.get_untracked().iter().next().copied(),
+        }
+    }
+}
@@ -1,28 +1,58 @@
 use leptos::{prelude::*, task::spawn_local};
-use tower::{
-    management_model::{ConfigListItem, UpdateConfigReq},
-    tauri_web::prelude::*,
-};
+use leptos_use::signal_debounced;
+use tower::{management_model::UpdateConfigReq, tauri_web::prelude::*};
 
 use crate::{
-    page::component::{AppSelect, StatusSelect},
-    service::async_update_config,
+    model::{
+        AuditResourceType, ConfigListItemExt, SubmitConfigChangeReq, UpdateConfigReqExt,
+        APP_VERSION_PATTERN,
+    },
+    page::{
+        component::{AppSelect, DictValueSelect, StatusSelect},
+        ActivityPane,
+    },
+    service::{async_submit_config_change, async_update_config},
 };
 
 #[component]
 pub(crate) fn UpdateConfigForm(
     open: RwSignal<bool>,
-    configs_resource: WebResult<Vec<ConfigListItem>>,
-    config: RwSignal<Option<ConfigListItem>>,
+    configs_resource: WebResult<Vec<ConfigListItemExt>>,
+    config: RwSignal<Option<ConfigListItemExt>>,
 ) -> impl IntoView {
     let app_state = expect_context::<AppState>();
+    let protected = config
+        .get_untracked()
+        .is_some_and(|config| config.is_protected());
     let form = UpdateConfigFormData::new(config);
+    let config_id = form.id;
+    let dict_code = Signal::derive({
+        let dict_code = signal_debounced(form.dict_code, 500.0);
+        move || str_trim_to_option(dict_code.get())
+    });
+    let reason = RwSignal::new("".to_string());
     let op_tiper = OpTiper::new();
     view! {
         <form on:submit=move |ev| {
             ev.prevent_default();
             let req = form.to_req();
             spawn_local(async move {
+                if protected {
+                    let resp = async_submit_config_change(SubmitConfigChangeReq {
+                            change: req,
+                            reason: str_trim_to_option(reason.get_untracked()),
+                        })
+                        .await;
+                    tip_or(
+                        resp,
+                        op_tiper.0,
+                        |_| {
+                            open.set(false);
+                            app_state.success("已提交变更申请，等待审批。");
+                        },
+                    );
+                    return;
+                }
                 let resp = async_update_config(req).await;
                 tip_or(
                     resp,
@@ -39,6 +69,16 @@ pub(crate) fn UpdateConfigForm(
                 <label class="label">应用</label>
                 <AppSelect selected=form.app />
                 <p class="validator-hint">必须输入</p>
+                <label class="label">应用版本</label>
+                <input
+                    type="text"
+                    class="w-full input validator"
+                    maxlength="20"
+                    pattern=APP_VERSION_PATTERN
+                    placeholder="留空对所有版本生效，如 >=1.4、1.3.2"
+                    bind:value=form.app_version
+                />
+                <p class="validator-hint">"版本格式：[>=|>|<=|<|=]主版本[.次版本[.修订号]]"</p>
                 <label class="label">配置名称</label>
                 <input
                     type="text"
@@ -49,19 +89,57 @@ pub(crate) fn UpdateConfigForm(
                     bind:value=form.var_name
                 />
                 <p class="validator-hint">必须输入</p>
-                <label class="label">配置值</label>
+                <label class="label">引用字典</label>
                 <input
                     type="text"
-                    class="w-full input validator"
-                    minlength="1"
+                    class="w-full input"
                     maxlength="50"
-                    required
-                    bind:value=form.var_value
+                    placeholder="字典代码，填写后配置值只能从字典项中选择"
+                    bind:value=form.dict_code
                 />
+                <label class="label">配置值</label>
+                <Show
+                    when=move || dict_code.get().is_some() && form.secret.get() == YesNo::No
+                    fallback=move || {
+                        view! {
+                            <Show
+                                when=move || form.secret.get() == YesNo::Yes
+                                fallback=move || {
+                                    view! {
+                                        <input
+                                            type="text"
+                                            class="w-full input validator"
+                                            minlength="1"
+                                            maxlength="50"
+                                            required
+                                            bind:value=form.var_value
+                                        />
+                                    }
+                                }
+                            >
+                                <input
+                                    type="password"
+                                    class="w-full input validator"
+                                    maxlength="50"
+                                    placeholder="留空则不修改"
+                                    bind:value=form.var_value
+                                />
+                            </Show>
+                        }
+                    }
+                >
+                    <DictValueSelect class="w-full" dict_code selected=form.var_value />
+                </Show>
                 <p class="validator-hint">必须输入</p>
                 <label class="label">是否公共</label>
                 <StatusSelect selected=form.public placeholder="是否公共" />
                 <p class="validator-hint">必须输入</p>
+                <label class="label">是否密钥</label>
+                <StatusSelect selected=form.secret placeholder="是否密钥" />
+                <p class="validator-hint">必须输入</p>
+                <label class="label">是否受保护</label>
+                <StatusSelect selected=form.protected placeholder="是否受保护" />
+                <p class="validator-hint">"受保护配置的修改需要审批后生效"</p>
                 <label class="label">是否有效</label>
                 <StatusSelect selected=form.status placeholder="是否有效" />
                 <p class="validator-hint">必须输入</p>
@@ -73,52 +151,90 @@ pub(crate) fn UpdateConfigForm(
                 >
                     {move || form.remark.get()}
                 </textarea>
+                <Show when=move || protected>
+                    <label class="mt-4 mb-2 label">变更说明</label>
+                    <textarea
+                        class="w-full textarea validator"
+                        maxlength="500"
+                        required
+                        placeholder="受保护配置，修改需要其他管理员审批后生效"
+                        on:input:target=move |ev| { reason.set(ev.target().value()) }
+                    >
+                        {move || reason.get_untracked()}
+                    </textarea>
+                    <p class="validator-hint">必须输入</p>
+                </Show>
                 <OpTip content=op_tiper.0 />
                 <button class="mt-4 btn btn-neutral" type="submit">
                     确定
                 </button>
             </fieldset>
         </form>
+        <ActivityPane
+            resource_types=vec![AuditResourceType::Config]
+            child_types=vec![AuditResourceType::ConfigSchedule, AuditResourceType::ConfigChange]
+            target_id=Signal::derive(move || Some(config_id.get().to_string()))
+        />
     }
 }
 
 struct UpdateConfigFormData {
     id: Signal<i32>,
     app: RwSignal<App>,
-    app_version: RwSignal<Option<String>>,
+    app_version: RwSignal<String>,
     var_name: RwSignal<String>,
     var_value: RwSignal<String>,
     public: RwSignal<YesNo>,
+    secret: RwSignal<YesNo>,
+    protected: RwSignal<YesNo>,
+    dict_code: RwSignal<String>,
     remark: RwSignal<String>,
     status: RwSignal<YesNo>,
 }
 impl UpdateConfigFormData {
-    fn new(config: RwSignal<Option<ConfigListItem>>) -> Self {
+    fn new(config: RwSignal<Option<ConfigListItemExt>>) -> Self {
         let config = config.get_untracked().unwrap();
+        let secret = config.secret;
+        let protected = config.protected;
+        let dict_code = config.dict_code.clone();
+        let var_value = if config.is_secret() {
+            "".to_string()
+        } else {
+            config.var_value.clone()
+        };
+        let config = config.base;
         Self {
             id: Signal::from(config.id),
             app: RwSignal::new(config.app),
-            app_version: RwSignal::new(config.app_version),
+            app_version: RwSignal::new(config.app_version.unwrap_or_default()),
             var_name: RwSignal::new(config.var_name),
-            var_value: RwSignal::new(config.var_value),
+            var_value: RwSignal::new(var_value),
             public: RwSignal::new(config.public),
+            secret: RwSignal::new(secret),
+            protected: RwSignal::new(protected),
+            dict_code: RwSignal::new(dict_code.unwrap_or_default()),
             remark: RwSignal::new(config.remark.unwrap_or_default()),
             status: RwSignal::new(config.status),
         }
     }
-    fn to_req(&self) -> UpdateConfigReq {
-        UpdateConfigReq {
-            id: self.id.get_untracked(),
-            app_id: self.app.get_untracked().to_string(),
-            app_version: self.app_version.get_untracked(),
-            var_name: self.var_name.get_untracked(),
-            var_value: self.var_value.get_untracked(),
-            public: self.public.get_untracked(),
-            remark: {
-                let remark = self.remark.get_untracked();
-                (remark.len() > 0).then(|| remark)
+    fn to_req(&self) -> UpdateConfigReqExt {
+        UpdateConfigReqExt {
+            base: UpdateConfigReq {
+                id: self.id.get_untracked(),
+                app_id: self.app.get_untracked().to_string(),
+                app_version: str_trim_to_option(self.app_version.get_untracked()),
+                var_name: self.var_name.get_untracked(),
+                var_value: self.var_value.get_untracked(),
+                public: self.public.get_untracked(),
+                remark: {
+                    let remark = self.remark.get_untracked();
+                    (remark.len() > 0).then(|| remark)
+                },
+                status: self.status.get_untracked(),
             },
-            status: self.status.get_untracked(),
+            secret: self.secret.get_untracked(),
+            protected: self.protected.get_untracked(),
+            dict_code: str_trim_to_option(self.dict_code.get_untracked()),
         }
     }
 }
@@ -2,11 +2,15 @@ use leptos::{prelude::*, task::spawn_local};
 use tower::{
     management_model::{AddDictReq, DictListItem},
     tauri_web::prelude::*,
-    web_model::common::str_trim_to_option,
+    web_model::{common::str_trim_to_option, OpTipData, OpTipType},
 };
 
 use crate::{
-    page::{component::StatusSelect, DictState},
+    model::{AddDictReqExt, LocaleLabels},
+    page::{
+        component::{LocaleTabs, StatusSelect},
+        DictExtFieldRow, DictExtSchemaEditor, DictState,
+    },
     service::async_add_dict,
 };
 
@@ -27,7 +31,21 @@ pub(crate) fn AddDictForm(
     view! {
         <form on:submit=move |ev| {
             ev.prevent_default();
-            let req = form.to_req();
+            let req = match form.to_req() {
+                Ok(req) => req,
+                Err(content) => {
+                    op_tiper
+                        .0
+                        .set(
+                            Some(OpTipData {
+                                typ: OpTipType::Warning,
+                                class: "".into(),
+                                content,
+                            }),
+                        );
+                    return;
+                }
+            };
             spawn_local(async move {
                 let resp = async_add_dict(req).await;
                 tip_or(
@@ -53,14 +71,7 @@ pub(crate) fn AddDictForm(
                 />
                 <p class="validator-hint">必须输入</p>
                 <label class="label">名称</label>
-                <input
-                    type="text"
-                    class="w-full input validator"
-                    minlength="1"
-                    maxlength="50"
-                    required
-                    bind:value=form.dict_name
-                />
+                <LocaleTabs name=form.dict_name labels=form.labels />
                 <p class="validator-hint">必须输入</p>
                 <label class="label">上级代码</label>
                 <input
@@ -79,6 +90,7 @@ pub(crate) fn AddDictForm(
                 <label class="label">是否有效</label>
                 <StatusSelect selected=form.status placeholder="是否有效" />
                 <p class="validator-hint">必须输入</p>
+                <DictExtSchemaEditor rows=form.ext_schema />
                 <label class="mt-4 mb-2 label">备注</label>
                 <textarea
                     class="w-full textarea validator"
@@ -103,6 +115,8 @@ struct AddDictFormData {
     public: RwSignal<YesNo>,
     remark: RwSignal<String>,
     status: RwSignal<YesNo>,
+    labels: RwSignal<LocaleLabels>,
+    ext_schema: RwSignal<Vec<DictExtFieldRow>>,
 }
 impl AddDictFormData {
     fn new(parent_code: Option<String>) -> Self {
@@ -113,16 +127,22 @@ impl AddDictFormData {
             public: RwSignal::new(YesNo::Yes),
             remark: RwSignal::new("".to_string()),
             status: RwSignal::new(YesNo::Yes),
+            labels: RwSignal::new(LocaleLabels::new()),
+            ext_schema: RwSignal::new(vec![]),
         }
     }
-    fn to_req(&self) -> AddDictReq {
-        AddDictReq {
-            dict_code: self.dict_code.get_untracked(),
-            dict_name: self.dict_name.get_untracked(),
-            parent_code: str_trim_to_option(self.parent_code.get_untracked()),
-            public: self.public.get_untracked(),
-            remark: str_trim_to_option(self.remark.get_untracked()),
-            status: self.status.get_untracked(),
-        }
+    fn to_req(&self) -> Result<AddDictReqExt, String> {
+        Ok(AddDictReqExt {
+            base: AddDictReq {
+                dict_code: self.dict_code.get_untracked(),
+                dict_name: self.dict_name.get_untracked(),
+                parent_code: str_trim_to_option(self.parent_code.get_untracked()),
+                public: self.public.get_untracked(),
+                remark: str_trim_to_option(self.remark.get_untracked()),
+                status: self.status.get_untracked(),
+            },
+            labels: self.labels.get_untracked(),
+            ext_schema: DictExtFieldRow::to_schema(&self.ext_schema.get_untracked())?,
+        })
     }
 }
@@ -1,27 +1,61 @@
 use leptos::{prelude::*, task::spawn_local};
 use tower::{
-    management_model::{AddDictItemReq, DictItemListItem, DictListItem},
+    management_model::{AddDictItemReq, DictListItem},
     tauri_web::prelude::*,
+    web_model::{OpTipData, OpTipType},
 };
 
 use crate::{
-    page::{component::StatusSelect, DictState},
-    service::async_add_dict_item,
+    model::{
+        check_validity_window, dict_item_rows, parse_dict_ext, AddDictItemReqExt, DictExtField,
+        DictItemListItemExt, LocaleLabels,
+    },
+    page::{
+        component::{LocaleTabs, StatusSelect},
+        datetime_local_to_secs, DictItemExtInput, DictItemStyleFields, DictItemValidityFields,
+        DictLoadStatus, DictState,
+    },
+    service::{async_add_dict_item, async_get_dict_ext_schema},
 };
 
 #[component]
 pub(crate) fn AddItemForm(
     open: RwSignal<bool>,
-    item_resource: WebResult<Vec<DictItemListItem>>,
+    item_resource: WebResult<Vec<DictItemListItemExt>>,
+    #[prop(optional)] parent_id: Option<i32>,
 ) -> impl IntoView {
     let app_state = expect_context::<AppState>();
     let state = expect_context::<DictState>();
-    let form = AddItemFormData::new(state.current_dict.get_untracked().unwrap());
+    let form = AddItemFormData::new(state.current_dict.get_untracked().unwrap(), parent_id);
+    let parent = form.parent_id;
+    let ext_schema = form.ext_schema;
+    let dict_code = form.dict_code.get_untracked();
+    let schema_loaded = RwSignal::new(None::<Result<(), String>>);
+    spawn_local(async move {
+        let result = async_get_dict_ext_schema(dict_code)
+            .await
+            .map(|schema| ext_schema.set(schema));
+        schema_loaded.set(Some(result.map_err(|err| err.to_string())));
+    });
     let op_tiper = OpTiper::new();
     view! {
         <form on:submit=move |ev| {
             ev.prevent_default();
-            let req = form.to_req();
+            let req = match form.to_req() {
+                Ok(req) => req,
+                Err(content) => {
+                    op_tiper
+                        .0
+                        .set(
+                            Some(OpTipData {
+                                typ: OpTipType::Warning,
+                                class: "".into(),
+                                content,
+                            }),
+                        );
+                    return;
+                }
+            };
             spawn_local(async move {
                 let resp = async_add_dict_item(req).await;
                 tip_or(
@@ -47,15 +81,47 @@ pub(crate) fn AddItemForm(
                     prop:value=form.dict_code
                 />
                 <p class="validator-hint">必须输入</p>
+                <label class="label">上级字典项</label>
+                <Suspense fallback=move || {
+                    view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+                }>
+                    {move || {
+                        item_resource
+                            .to_view(move |list| {
+                                view! {
+                                    <select
+                                        class="w-full select"
+                                        on:change:target=move |ev| {
+                                            parent.set(ev.target().value().parse().ok());
+                                        }
+                                        prop:value=move || {
+                                            parent.get().map(|id| id.to_string()).unwrap_or_default()
+                                        }
+                                    >
+                                        <option value="">"（无）"</option>
+                                        {dict_item_rows(&list)
+                                            .into_iter()
+                                            .map(|row| {
+                                                view! {
+                                                    <option value=row.item.id.to_string()>
+                                                        {format!(
+                                                            "{}{}（{}）",
+                                                            "　".repeat(row.depth),
+                                                            row.item.item_name,
+                                                            row.item.item_value,
+                                                        )}
+                                                    </option>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </select>
+                                }
+                                    .into_any()
+                            })
+                    }}
+                </Suspense>
                 <label class="label">名称</label>
-                <input
-                    type="text"
-                    class="w-full input validator"
-                    minlength="1"
-                    maxlength="50"
-                    required
-                    bind:value=form.item_name
-                />
+                <LocaleTabs name=form.item_name labels=form.labels />
                 <p class="validator-hint">必须输入</p>
                 <label class="label">值</label>
                 <input
@@ -73,6 +139,9 @@ pub(crate) fn AddItemForm(
                 <label class="label">是否有效</label>
                 <StatusSelect selected=form.status placeholder="是否有效" />
                 <p class="validator-hint">必须输入</p>
+                <DictItemStyleFields name=form.item_name color=form.color icon=form.icon />
+                <DictItemValidityFields valid_from=form.valid_from valid_until=form.valid_until />
+                <DictItemExtInput ext=form.ext schema=form.ext_schema />
                 <label class="mt-4 mb-2 label">备注</label>
                 <textarea
                     class="w-full textarea validator"
@@ -81,8 +150,13 @@ pub(crate) fn AddItemForm(
                 >
                     {move || form.remark.get()}
                 </textarea>
+                <DictLoadStatus loaded=schema_loaded what="扩展字段定义" />
                 <OpTip content=op_tiper.0 />
-                <button class="mt-4 btn btn-neutral" type="submit">
+                <button
+                    class="mt-4 btn btn-neutral"
+                    type="submit"
+                    disabled=move || !matches!(schema_loaded.get(), Some(Ok(_)))
+                >
                     确定
                 </button>
             </fieldset>
@@ -97,9 +171,17 @@ struct AddItemFormData {
     public: RwSignal<YesNo>,
     remark: RwSignal<String>,
     status: RwSignal<YesNo>,
+    labels: RwSignal<LocaleLabels>,
+    parent_id: RwSignal<Option<i32>>,
+    color: RwSignal<Option<String>>,
+    icon: RwSignal<Option<String>>,
+    ext: RwSignal<String>,
+    ext_schema: RwSignal<Vec<DictExtField>>,
+    valid_from: RwSignal<String>,
+    valid_until: RwSignal<String>,
 }
 impl AddItemFormData {
-    fn new(dict: DictListItem) -> Self {
+    fn new(dict: DictListItem, parent_id: Option<i32>) -> Self {
         Self {
             dict_code: Signal::from(dict.dict_code),
             item_name: RwSignal::new("".to_string()),
@@ -107,16 +189,39 @@ impl AddItemFormData {
             public: RwSignal::new(YesNo::Yes),
             remark: RwSignal::new("".to_string()),
             status: RwSignal::new(YesNo::Yes),
+            labels: RwSignal::new(LocaleLabels::new()),
+            parent_id: RwSignal::new(parent_id),
+            color: RwSignal::new(None),
+            icon: RwSignal::new(None),
+            ext: RwSignal::new("".to_string()),
+            ext_schema: RwSignal::new(vec![]),
+            valid_from: RwSignal::new("".to_string()),
+            valid_until: RwSignal::new("".to_string()),
         }
     }
-    fn to_req(&self) -> AddDictItemReq {
-        AddDictItemReq {
-            dict_code: self.dict_code.get_untracked(),
-            item_name: self.item_name.get_untracked(),
-            item_value: self.item_value.get_untracked(),
-            public: self.public.get_untracked(),
-            remark: str_trim_to_option(self.remark.get_untracked()),
-            status: self.status.get_untracked(),
-        }
+    fn to_req(&self) -> Result<AddDictItemReqExt, String> {
+        let ext = self
+            .ext_schema
+            .with_untracked(|schema| parse_dict_ext(schema, &self.ext.get_untracked()))?;
+        let valid_from = datetime_local_to_secs(&self.valid_from.get_untracked());
+        let valid_until = datetime_local_to_secs(&self.valid_until.get_untracked());
+        check_validity_window(valid_from, valid_until)?;
+        Ok(AddDictItemReqExt {
+            base: AddDictItemReq {
+                dict_code: self.dict_code.get_untracked(),
+                item_name: self.item_name.get_untracked(),
+                item_value: self.item_value.get_untracked(),
+                public: self.public.get_untracked(),
+                remark: str_trim_to_option(self.remark.get_untracked()),
+                status: self.status.get_untracked(),
+            },
+            labels: self.labels.get_untracked(),
+            parent_id: self.parent_id.get_untracked(),
+            color: self.color.get_untracked(),
+            icon: self.icon.get_untracked(),
+            ext,
+            valid_from,
+            valid_until,
+        })
     }
 }
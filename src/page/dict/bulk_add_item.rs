@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use leptos::{prelude::*, task::spawn_local};
+use tower::{management_model::AddDictItemReq, tauri_web::prelude::*};
+
+use crate::{
+    model::{parse_bulk_items, AddDictItemReqExt, BulkItemLine, DictItemListItemExt, LocaleLabels},
+    page::DictState,
+    service::{async_add_dict_item, async_get_dict_items},
+};
+
+/// 粘贴多行字典项，预览后逐行新增，单行失败不影响其他行
+#[component]
+pub(crate) fn BulkAddItemForm(item_resource: WebResult<Vec<DictItemListItemExt>>) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    let state = expect_context::<DictState>();
+    let dict_code = state.current_dict.get_untracked().unwrap().dict_code;
+    let content = RwSignal::new(String::new());
+    let lines = RwSignal::new(None::<Vec<BulkItemLine>>);
+    // 行号 -> 新增结果
+    let results = RwSignal::new(HashMap::<usize, Result<(), String>>::new());
+    let adding = RwSignal::new(false);
+    let op_tiper = OpTiper::new();
+    let preview = {
+        let dict_code = dict_code.clone();
+        move |_| {
+            let dict_code = dict_code.clone();
+            results.set(HashMap::new());
+            spawn_local(async move {
+                let resp = async_get_dict_items(Some(dict_code)).await;
+                tip_or(resp, op_tiper.0, |existing| {
+                    lines.set(Some(parse_bulk_items(&content.get_untracked(), &existing)));
+                });
+            });
+        }
+    };
+    let valid_count = move || {
+        lines.with(|lines| {
+            lines.as_ref().map_or(0, |lines| {
+                lines.iter().filter(|line| line.problem.is_none()).count()
+            })
+        })
+    };
+    view! {
+        <form on:submit=move |ev| {
+            ev.prevent_default();
+            let Some(lines) = lines.get_untracked() else {
+                return;
+            };
+            let dict_code = dict_code.clone();
+            adding.set(true);
+            spawn_local(async move {
+                let mut added = 0;
+                for line in lines.into_iter().filter(|line| line.problem.is_none()) {
+                    let req = AddDictItemReqExt {
+                        base: AddDictItemReq {
+                            dict_code: dict_code.clone(),
+                            item_name: line.item_name,
+                            item_value: line.item_value,
+                            public: YesNo::Yes,
+                            remark: line.remark,
+                            status: YesNo::Yes,
+                        },
+                        labels: LocaleLabels::new(),
+                        parent_id: None,
+                        color: None,
+                        icon: None,
+                        ext: None,
+                        valid_from: None,
+                        valid_until: None,
+                    };
+                    let result = async_add_dict_item(req).await.map_err(|err| err.to_string());
+                    if result.is_ok() {
+                        added += 1;
+                    }
+                    results
+                        .update(|results| {
+                            results.insert(line.line, result);
+                        });
+                }
+                adding.set(false);
+                item_resource.refetch();
+                app_state.success(&format!("批量新增完成，成功{}条。", added));
+            });
+        }>
+            <fieldset class="p-4 w-full fieldset">
+                <label class="mb-2 label">"每行“名称,值[,备注]”，可直接粘贴Excel中的三列"</label>
+                <textarea
+                    class="w-full h-48 font-mono textarea"
+                    placeholder="男,male,备注"
+                    on:input:target=move |ev| {
+                        content.set(ev.target().value());
+                        lines.set(None);
+                    }
+                >
+                    {move || content.get_untracked()}
+                </textarea>
+                <button class="mt-2 btn" type="button" on:click=preview>
+                    预览
+                </button>
+                {move || {
+                    lines
+                        .get()
+                        .map(|lines| {
+                            view! {
+                                <table class="table mt-4 table-xs">
+                                    <thead>
+                                        <tr>
+                                            <th>行</th>
+                                            <th>名称</th>
+                                            <th>值</th>
+                                            <th>备注</th>
+                                            <th>结果</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        {lines
+                                            .into_iter()
+                                            .map(|line| {
+                                                let number = line.line;
+                                                let problem = line.problem.clone();
+                                                let row_class = if problem.is_some() { "bg-error/10" } else { "" };
+                                                view! {
+                                                    <tr class=row_class>
+                                                        <td>{number}</td>
+                                                        <td>{line.item_name}</td>
+                                                        <td>{line.item_value}</td>
+                                                        <td>{line.remark}</td>
+                                                        <td>
+                                                            {move || {
+                                                                match (&problem, results.with(|results| results.get(&number).cloned())) {
+                                                                    (Some(problem), _) => {
+                                                                        view! { <span class="badge badge-error">{problem.clone()}</span> }
+                                                                            .into_any()
+                                                                    }
+                                                                    (None, Some(Ok(_))) => {
+                                                                        view! { <span class="badge badge-success">成功</span> }
+                                                                            .into_any()
+                                                                    }
+                                                                    (None, Some(Err(err))) => {
+                                                                        view! { <span class="badge badge-warning">{err}</span> }
+                                                                            .into_any()
+                                                                    }
+                                                                    (None, None) => {
+                                                                        view! { <span class="badge badge-ghost">待新增</span> }
+                                                                            .into_any()
+                                                                    }
+                                                                }
+                                                            }}
+                                                        </td>
+                                                    </tr>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </tbody>
+                                </table>
+                            }
+                        })
+                }}
+                <OpTip content=op_tiper.0 />
+                <button
+                    class="mt-4 btn btn-neutral"
+                    type="submit"
+                    disabled=move || adding.get() || !results.with(|results| results.is_empty()) || valid_count() == 0
+                >
+                    {move || format!("新增{}条", valid_count())}
+                </button>
+            </fieldset>
+        </form>
+    }
+}
@@ -0,0 +1,126 @@
+use leptos::{prelude::*, task::spawn_local};
+use tower::{management_model::DictListItem, tauri_web::prelude::*};
+
+use crate::{
+    model::{DeleteDictReq, DictUsageReq},
+    page::{DictState, DictUsageWarning},
+    service::{async_delete_dict, async_get_dict_items, async_get_dict_usage},
+};
+
+#[component]
+pub(crate) fn DeleteDictForm(
+    open: RwSignal<bool>,
+    dict_resource: WebResult<DictListItem>,
+) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    let state = expect_context::<DictState>();
+    let dict = state.target_dict.get_untracked().unwrap();
+    let dict_code = dict.dict_code.clone();
+    let children = dict.children.len();
+    let items_resource = LocalResource::new({
+        let dict_code = dict_code.clone();
+        move || {
+            let dict_code = dict_code.clone();
+            async move {
+                let items = async_get_dict_items(Some(dict_code.clone())).await?;
+                let usage = async_get_dict_usage(DictUsageReq {
+                    dict_code,
+                    item_value: None,
+                })
+                .await?;
+                Ok((items, usage))
+            }
+        }
+    });
+    let cascade = RwSignal::new(false);
+    let confirmed = RwSignal::new(false);
+    let op_tiper = OpTiper::new();
+    view! {
+        <form on:submit=move |ev| {
+            ev.prevent_default();
+            let dict_code = dict_code.clone();
+            let req = DeleteDictReq {
+                dict_code: dict_code.clone(),
+                cascade: cascade.get_untracked(),
+            };
+            spawn_local(async move {
+                let resp = async_delete_dict(req).await;
+                tip_or(
+                    resp,
+                    op_tiper.0,
+                    |_| {
+                        dict_resource.refetch();
+                        if state
+                            .current_dict
+                            .get_untracked()
+                            .is_some_and(|current| current.dict_code == dict_code)
+                        {
+                            state.current_dict.set(None);
+                        }
+                        open.set(false);
+                        app_state.success("删除字典成功，可在回收站恢复。");
+                    },
+                );
+            });
+        }>
+            <fieldset class="p-4 w-full fieldset">
+                <label class="label">字典</label>
+                <span>{format!("{}（{}）", dict.dict_name, dict.dict_code)}</span>
+                <OpTip content=op_tiper.0 />
+                <Suspense fallback=move || {
+                    view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+                }>
+                    {move || {
+                        items_resource
+                            .to_view(move |(items, usage)| {
+                                let has_usage = !usage.is_empty();
+                                if children == 0 && items.is_empty() && !has_usage {
+                                    return view! {
+                                        <p>"确认删除该字典？"</p>
+                                        <button class="mt-4 btn btn-neutral" type="submit">
+                                            删除
+                                        </button>
+                                    }
+                                        .into_any();
+                                }
+                                let has_children = children > 0 || !items.is_empty();
+                                view! {
+                                    {has_children
+                                        .then(|| {
+                                            view! {
+                                                <div class="alert alert-warning">
+                                                    {format!(
+                                                        "该字典下还有 {} 个下级字典、{} 个字典项，不能直接删除。",
+                                                        children,
+                                                        items.len(),
+                                                    )}
+                                                </div>
+                                                <label class="label">
+                                                    <input
+                                                        type="checkbox"
+                                                        class="checkbox"
+                                                        bind:checked=cascade
+                                                    />
+                                                    "同时删除全部下级字典及字典项"
+                                                </label>
+                                            }
+                                        })}
+                                    <DictUsageWarning usage confirmed />
+                                    <button
+                                        class="mt-4 btn btn-neutral"
+                                        type="submit"
+                                        disabled=move || {
+                                            (has_children && !cascade.get()) || (has_usage && !confirmed.get())
+                                        }
+                                    >
+                                        删除
+                                    </button>
+                                }
+                                    .into_any()
+                            })
+                    }}
+                </Suspense>
+            </fieldset>
+        </form>
+    }
+}
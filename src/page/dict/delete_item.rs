@@ -0,0 +1,97 @@
+use leptos::{prelude::*, task::spawn_local};
+use tower::tauri_web::prelude::*;
+
+use crate::{
+    model::{DictItemListItemExt, DictUsageReq},
+    page::DictUsageWarning,
+    service::{async_delete_dict_item, async_get_dict_items, async_get_dict_usage},
+};
+
+#[component]
+pub(crate) fn DeleteItemForm(
+    open: RwSignal<bool>,
+    item_resource: WebResult<Vec<DictItemListItemExt>>,
+    item: RwSignal<Option<DictItemListItemExt>>,
+) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    let item = item.get_untracked().unwrap();
+    let id = item.id;
+    // 删除上级会让下级失去上级，有下级时不能删除
+    let usage_resource = LocalResource::new({
+        let req = DictUsageReq {
+            dict_code: item.dict_code.clone(),
+            item_value: Some(item.item_value.clone()),
+        };
+        move || {
+            let req = req.clone();
+            async move {
+                let children: Vec<String> = async_get_dict_items(Some(req.dict_code.clone()))
+                    .await?
+                    .into_iter()
+                    .filter(|child| child.parent_id == Some(id))
+                    .map(|child| format!("{}（{}）", child.item_name, child.item_value))
+                    .collect();
+                let usage = async_get_dict_usage(req).await?;
+                Ok((children, usage))
+            }
+        }
+    });
+    let confirmed = RwSignal::new(false);
+    let op_tiper = OpTiper::new();
+    view! {
+        <form on:submit=move |ev| {
+            ev.prevent_default();
+            spawn_local(async move {
+                let resp = async_delete_dict_item(id).await;
+                tip_or(
+                    resp,
+                    op_tiper.0,
+                    |_| {
+                        item_resource.refetch();
+                        open.set(false);
+                        app_state.success("删除字典项成功，可在回收站恢复。");
+                    },
+                );
+            });
+        }>
+            <fieldset class="p-4 w-full fieldset">
+                <label class="label">字典项</label>
+                <span>{format!("{}（{}）", item.item_name, item.item_value)}</span>
+                <OpTip content=op_tiper.0 />
+                <Suspense fallback=move || {
+                    view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+                }>
+                    {move || {
+                        usage_resource
+                            .to_view(move |(children, usage)| {
+                                if !children.is_empty() {
+                                    return view! {
+                                        <div class="mt-2 alert alert-warning">
+                                            {format!(
+                                                "该字典项下还有{}个下级：{}，请先删除下级或调整其上级。",
+                                                children.len(),
+                                                children.join("，"),
+                                            )}
+                                        </div>
+                                    }
+                                        .into_any();
+                                }
+                                let has_usage = !usage.is_empty();
+                                view! {
+                                    <DictUsageWarning usage confirmed />
+                                    <button
+                                        class="mt-4 btn btn-neutral"
+                                        type="submit"
+                                        disabled=move || has_usage && !confirmed.get()
+                                    >
+                                        删除
+                                    </button>
+                                }
+                                    .into_any()
+                            })
+                    }}
+                </Suspense>
+            </fieldset>
+        </form>
+    }
+}
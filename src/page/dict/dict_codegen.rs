@@ -0,0 +1,67 @@
+use leptos::prelude::*;
+use tower::tauri_web::prelude::*;
+
+use crate::{
+    model::{dict_item_rows, dict_to_rust_enum, to_variant_name, DictCodegenItem},
+    page::DictState,
+    service::async_get_dict_items,
+};
+
+#[component]
+pub(crate) fn DictCodegenForm() -> impl IntoView {
+    let state = expect_context::<DictState>();
+    let dict = state.target_dict.get_untracked().unwrap();
+    let enum_name = RwSignal::new(to_variant_name(&dict.dict_code));
+    let items_resource = LocalResource::new({
+        let dict_code = dict.dict_code.clone();
+        move || async_get_dict_items(Some(dict_code.clone()))
+    });
+    view! {
+        <fieldset class="p-4 w-full fieldset">
+            <label class="label">枚举名</label>
+            <input type="text" class="w-full input" bind:value=enum_name />
+            <Suspense fallback=move || {
+                view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+            }>
+                {move || {
+                    items_resource
+                        .to_view(move |list| {
+                            let items: Vec<DictCodegenItem> = dict_item_rows(&list)
+                                .into_iter()
+                                .map(|row| DictCodegenItem {
+                                    value: row.item.item_value.clone(),
+                                    label: row.item.item_name.clone(),
+                                })
+                                .collect();
+                            let code = move || dict_to_rust_enum(&enum_name.get(), &items);
+                            view! {
+                                {move || match code() {
+                                    Ok(code) => {
+                                        let href = format!(
+                                            "data:text/plain;charset=utf-8,{}",
+                                            String::from(js_sys::encode_uri_component(&code)),
+                                        );
+                                        let file_name = format!("{}.rs", enum_name.get());
+                                        view! {
+                                            <textarea class="mt-2 w-full h-96 font-mono textarea" readonly>
+                                                {code}
+                                            </textarea>
+                                            <a class="mt-2 btn btn-link" href=href download=file_name.clone()>
+                                                {format!("下载 {}", file_name)}
+                                            </a>
+                                        }
+                                            .into_any()
+                                    }
+                                    Err(err) => {
+                                        view! { <p class="mt-2 text-error">{err}</p> }.into_any()
+                                    }
+                                }}
+                            }
+                                .into_any()
+                        })
+                }}
+            </Suspense>
+            <p class="label">"build.rs中可通过 model/dict_codegen.rs 的 dict_to_rust_enum 生成同样的代码"</p>
+        </fieldset>
+    }
+}
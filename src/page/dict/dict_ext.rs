@@ -0,0 +1,337 @@
+use leptos::prelude::*;
+use leptos_icons::Icon;
+use tower::tauri_web::prelude::*;
+
+use crate::model::{ConfigValueType, DictExtField};
+
+/// 字典项可选的图标，`(名称, 图标)`，名称保存到字典项的`icon`
+pub(crate) fn dict_item_icons() -> Vec<(&'static str, icondata::Icon)> {
+    vec![
+        ("AiStarOutlined", icondata::AiStarOutlined),
+        ("AiHeartOutlined", icondata::AiHeartOutlined),
+        ("AiFireOutlined", icondata::AiFireOutlined),
+        ("AiThunderboltOutlined", icondata::AiThunderboltOutlined),
+        ("AiCrownOutlined", icondata::AiCrownOutlined),
+        ("AiTrophyOutlined", icondata::AiTrophyOutlined),
+        ("AiGiftOutlined", icondata::AiGiftOutlined),
+        ("AiTagOutlined", icondata::AiTagOutlined),
+        ("AiFlagOutlined", icondata::AiFlagOutlined),
+        ("AiUserOutlined", icondata::AiUserOutlined),
+        ("AiLockOutlined", icondata::AiLockOutlined),
+        ("AiClockCircleOutlined", icondata::AiClockCircleOutlined),
+        ("AiCheckCircleOutlined", icondata::AiCheckCircleOutlined),
+        ("AiCloseCircleOutlined", icondata::AiCloseCircleOutlined),
+        ("AiInfoCircleOutlined", icondata::AiInfoCircleOutlined),
+        (
+            "AiExclamationCircleOutlined",
+            icondata::AiExclamationCircleOutlined,
+        ),
+    ]
+}
+
+fn dict_item_icon(name: &str) -> Option<icondata::Icon> {
+    dict_item_icons()
+        .into_iter()
+        .find(|(icon_name, _)| *icon_name == name)
+        .map(|(_, icon)| icon)
+}
+
+/// 按背景色的亮度选择黑色或白色文字
+fn badge_style(color: &str) -> String {
+    let hex = color.trim_start_matches('#');
+    let channel = |index: usize| {
+        hex.get(index..index + 2)
+            .and_then(|it| u8::from_str_radix(it, 16).ok())
+            .map(|it| it as f64)
+    };
+    match (hex.len(), channel(0), channel(2), channel(4)) {
+        (6, Some(r), Some(g), Some(b)) => {
+            let text = if r * 0.299 + g * 0.587 + b * 0.114 > 150.0 {
+                "#000000"
+            } else {
+                "#ffffff"
+            };
+            format!(
+                "background-color: {}; border-color: {}; color: {}",
+                color, color, text
+            )
+        }
+        _ => "".to_string(),
+    }
+}
+
+/// 字典项的徽标，与客户端的展示效果一致
+#[component]
+pub(crate) fn DictItemBadge(
+    #[prop(into)] name: Signal<String>,
+    #[prop(into)] color: Signal<Option<String>>,
+    #[prop(into)] icon: Signal<Option<String>>,
+) -> impl IntoView {
+    view! {
+        <span
+            class="gap-1 badge"
+            style=move || color.get().map(|color| badge_style(&color)).unwrap_or_default()
+        >
+            {move || {
+                icon.get()
+                    .and_then(|icon| dict_item_icon(&icon))
+                    .map(|icon| view! { <Icon icon width="1em" height="1em" /> })
+            }}
+            {move || name.get()}
+        </span>
+    }
+}
+
+/// 编辑字典项的颜色和图标，并实时预览徽标
+#[component]
+pub(crate) fn DictItemStyleFields(
+    name: RwSignal<String>,
+    color: RwSignal<Option<String>>,
+    icon: RwSignal<Option<String>>,
+) -> impl IntoView {
+    view! {
+        <label class="label">徽标</label>
+        <div class="flex gap-2 items-center">
+            <input
+                type="color"
+                class="p-1 w-12 input"
+                prop:value=move || color.get().unwrap_or_else(|| "#000000".to_string())
+                on:input:target=move |ev| color.set(Some(ev.target().value()))
+            />
+            <button
+                class="btn btn-xs btn-ghost"
+                type="button"
+                disabled=move || color.get().is_none()
+                on:click=move |_| color.set(None)
+            >
+                清除颜色
+            </button>
+            <select
+                class="select"
+                on:change:target=move |ev| icon.set(str_trim_to_option(ev.target().value()))
+                prop:value=move || icon.get().unwrap_or_default()
+            >
+                <option value="">"（无图标）"</option>
+                {dict_item_icons()
+                    .into_iter()
+                    .map(|(icon_name, _)| view! { <option value=icon_name>{icon_name}</option> })
+                    .collect_view()}
+            </select>
+            <DictItemBadge name color icon />
+        </div>
+    }
+}
+
+/// 按字典的扩展字段定义编辑字典项的`ext`
+#[component]
+pub(crate) fn DictItemExtInput(
+    ext: RwSignal<String>,
+    schema: RwSignal<Vec<DictExtField>>,
+) -> impl IntoView {
+    let placeholder = move || {
+        schema.with(|schema| {
+            if schema.is_empty() {
+                return "JSON对象，如 {\"key\": \"value\"}".to_string();
+            }
+            let fields: Vec<String> = schema
+                .iter()
+                .map(|field| {
+                    format!(
+                        "\"{}\": {}{}",
+                        field.name,
+                        field.value_type.label(),
+                        if field.required { "（必填）" } else { "" }
+                    )
+                })
+                .collect();
+            format!("{{{}}}", fields.join(", "))
+        })
+    };
+    view! {
+        <label class="mt-4 mb-2 label">扩展字段</label>
+        <textarea
+            class="w-full h-24 font-mono textarea"
+            placeholder=placeholder
+            on:input:target=move |ev| ext.set(ev.target().value())
+        >
+            {move || ext.get_untracked()}
+        </textarea>
+        {move || {
+            schema
+                .with(|schema| {
+                    schema
+                        .iter()
+                        .filter_map(|field| {
+                            field
+                                .remark
+                                .as_ref()
+                                .map(|remark| {
+                                    view! { <p class="label">{format!("{}：{}", field.name, remark)}</p> }
+                                })
+                        })
+                        .collect_view()
+                })
+        }}
+    }
+}
+
+/// 扩展字段定义等表单依赖的加载状态；加载成功前不能提交，否则会跳过校验或用空值覆盖
+#[component]
+pub(crate) fn DictLoadStatus(
+    loaded: RwSignal<Option<Result<(), String>>>,
+    what: &'static str,
+) -> impl IntoView {
+    move || match loaded.get() {
+        None => view! { <span class="mt-2 loading loading-dots"></span> }.into_any(),
+        Some(Ok(_)) => view! {}.into_any(),
+        Some(Err(err)) => view! {
+            <div class="mt-2 alert alert-error">
+                {format!("加载{}失败：{}，暂时不能提交。", what, err)}
+            </div>
+        }
+        .into_any(),
+    }
+}
+
+/// 编辑字典的扩展字段定义
+#[component]
+pub(crate) fn DictExtSchemaEditor(rows: RwSignal<Vec<DictExtFieldRow>>) -> impl IntoView {
+    view! {
+        <label class="label">字典项扩展字段</label>
+        <table class="table table-xs">
+            <thead>
+                <tr>
+                    <th>名称</th>
+                    <th>类型</th>
+                    <th>必填</th>
+                    <th>备注</th>
+                    <th></th>
+                </tr>
+            </thead>
+            <tbody>
+                {move || {
+                    rows.get()
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, row)| {
+                            view! {
+                                <tr>
+                                    <td>
+                                        <input
+                                            type="text"
+                                            class="input input-xs validator"
+                                            minlength="1"
+                                            maxlength="50"
+                                            required
+                                            bind:value=row.name
+                                        />
+                                    </td>
+                                    <td>
+                                        <select
+                                            class="select select-xs"
+                                            on:change:target=move |ev| {
+                                                row.value_type
+                                                    .set(ev.target().value().try_into().unwrap_or_default());
+                                            }
+                                            prop:value=move || row.value_type.get().to_string()
+                                        >
+                                            {ConfigValueType::all()
+                                                .into_iter()
+                                                .map(|value_type| {
+                                                    view! {
+                                                        <option value=value_type.to_string()>
+                                                            {value_type.label()}
+                                                        </option>
+                                                    }
+                                                })
+                                                .collect_view()}
+                                        </select>
+                                    </td>
+                                    <td>
+                                        <input
+                                            type="checkbox"
+                                            class="checkbox checkbox-xs"
+                                            bind:checked=row.required
+                                        />
+                                    </td>
+                                    <td>
+                                        <input
+                                            type="text"
+                                            class="input input-xs"
+                                            maxlength="500"
+                                            bind:value=row.remark
+                                        />
+                                    </td>
+                                    <td>
+                                        <button
+                                            class="btn btn-xs btn-ghost"
+                                            type="button"
+                                            on:click=move |_| {
+                                                rows.update(|rows| {
+                                                    rows.remove(index);
+                                                });
+                                            }
+                                        >
+                                            删除
+                                        </button>
+                                    </td>
+                                </tr>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </tbody>
+        </table>
+        <button
+            class="btn btn-xs"
+            type="button"
+            on:click=move |_| rows.update(|rows| rows.push(DictExtFieldRow::new(None)))
+        >
+            添加扩展字段
+        </button>
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DictExtFieldRow {
+    name: RwSignal<String>,
+    value_type: RwSignal<ConfigValueType>,
+    required: RwSignal<bool>,
+    remark: RwSignal<String>,
+}
+impl DictExtFieldRow {
+    pub(crate) fn new(field: Option<DictExtField>) -> Self {
+        let field = field.unwrap_or(DictExtField {
+            name: "".to_string(),
+            value_type: ConfigValueType::String,
+            required: false,
+            remark: None,
+        });
+        Self {
+            name: RwSignal::new(field.name),
+            value_type: RwSignal::new(field.value_type),
+            required: RwSignal::new(field.required),
+            remark: RwSignal::new(field.remark.unwrap_or_default()),
+        }
+    }
+    /// 字段名不能为空且不能重复
+    pub(crate) fn to_schema(rows: &[DictExtFieldRow]) -> Result<Vec<DictExtField>, String> {
+        let mut schema: Vec<DictExtField> = vec![];
+        for row in rows {
+            let name = row.name.get_untracked().trim().to_string();
+            if name.is_empty() {
+                return Err("扩展字段名称不能为空".to_string());
+            }
+            if schema.iter().any(|field| field.name == name) {
+                return Err(format!("扩展字段{}重复", name));
+            }
+            schema.push(DictExtField {
+                name,
+                value_type: row.value_type.get_untracked(),
+                required: row.required.get_untracked(),
+                remark: str_trim_to_option(row.remark.get_untracked()),
+            });
+        }
+        Ok(schema)
+    }
+}
@@ -1,9 +1,17 @@
-use leptos::{logging::log, prelude::*};
-use tower::{management_model::DictListItem, web::common::WebResultExt};
+use leptos::{logging::log, prelude::*, task::spawn_local};
+use tower::{
+    management_model::{DictListItem, UpdateDictReq},
+    web::common::WebResultExt,
+};
 
 use crate::{
-    page::{AddDictForm, DictState, UpdateDictForm},
-    service::async_get_dicts,
+    model::{SortDictsReq, UpdateDictReqExt},
+    page::{
+        contains_dict, move_before, parent_codes, split_match, subtree_matches, AddDictForm,
+        DeleteDictForm, DictCodegenForm, DictState, DictTrash, DictUsagePanel, ExportDictForm,
+        ImportDictForm, UpdateDictForm,
+    },
+    service::{async_get_dicts, async_sort_dicts, async_update_dict},
 };
 use tower::tauri_web::prelude::*;
 
@@ -11,6 +19,8 @@ use tower::tauri_web::prelude::*;
 pub fn DictMenu(#[prop(into,default="".into())] class: String) -> impl IntoView {
     let state = expect_context::<DictState>();
     let dict_resource = LocalResource::new(move || async_get_dicts());
+    provide_context(dict_resource);
+    let app_state = expect_context::<AppState>();
     view! {
         <Suspense fallback=move || {
             view! { <span class="self-center loading loading-spinner loading-xl"></span> }
@@ -18,10 +28,49 @@ pub fn DictMenu(#[prop(into,default="".into())] class: String) -> impl IntoView
             {move || {
                 dict_resource
                     .to_view(|dict| {
+                        let all_parents = parent_codes(&dict);
                         view! {
                             <ul class=format!("menu rounded-box  {}", class)>
                                 <li>
-                                    <span>
+                                    <input
+                                        type="search"
+                                        class="input input-sm"
+                                        placeholder="搜索代码或名称"
+                                        bind:value=state.keyword
+                                    />
+                                </li>
+                                <li>
+                                    <span class="flex gap-2">
+                                        <button
+                                            class="btn btn-xs"
+                                            on:click=move |_| state.collapsed.set(Default::default())
+                                        >
+                                            全部展开
+                                        </button>
+                                        <button
+                                            class="btn btn-xs"
+                                            on:click=move |_| {
+                                                state.collapsed.set(all_parents.iter().cloned().collect())
+                                            }
+                                        >
+                                            全部折叠
+                                        </button>
+                                    </span>
+                                </li>
+                                <li>
+                                    <span
+                                        title="拖放到此处移为顶级字典"
+                                        on:dragover=move |ev| ev.prevent_default()
+                                        on:drop=move |ev| {
+                                            ev.prevent_default();
+                                            if let Some(source) = state.dragging_dict.get_untracked() {
+                                                state.dragging_dict.set(None);
+                                                if source.parent_code.is_some() {
+                                                    move_dict(app_state, dict_resource, source, None);
+                                                }
+                                            }
+                                        }
+                                    >
                                         <button
                                             class="btn btn-xs"
                                             on:click=move |ev| {
@@ -32,15 +81,28 @@ pub fn DictMenu(#[prop(into,default="".into())] class: String) -> impl IntoView
                                         >
                                             新增子项
                                         </button>
+                                        <button
+                                            class="btn btn-xs"
+                                            on:click=move |ev| {
+                                                ev.stop_propagation();
+                                                state.open_dict_trash.set(true);
+                                            }
+                                        >
+                                            回收站
+                                        </button>
                                     </span>
                                 </li>
-                                {dict
-                                    .children
-                                    .iter()
-                                    .map(|dict| {
-                                        view! { <DictLi dict=dict.to_owned() /> }
-                                    })
-                                    .collect_view()}
+                                {
+                                    let siblings = sibling_codes(&dict);
+                                    dict.children
+                                        .iter()
+                                        .map(|dict| {
+                                            view! {
+                                                <DictLi dict=dict.to_owned() siblings=siblings.clone() />
+                                            }
+                                        })
+                                        .collect_view()
+                                }
                             </ul>
                         }
                             .into_any()
@@ -61,23 +123,121 @@ pub fn DictMenu(#[prop(into,default="".into())] class: String) -> impl IntoView
                 <UpdateDictForm open=state.open_update_dict dict_resource />
             </Dialog>
         </Show>
+        <Show
+            when=move || { state.target_dict.get().is_some() && state.open_delete_dict.get() }
+            fallback=|| view! {}
+        >
+            <Dialog open=state.open_delete_dict title="删除字典">
+                <DeleteDictForm open=state.open_delete_dict dict_resource />
+            </Dialog>
+        </Show>
+        <Show when=move || { state.open_dict_trash.get() } fallback=|| view! {}>
+            <Dialog open=state.open_dict_trash title="字典回收站">
+                <DictTrash dict_resource />
+            </Dialog>
+        </Show>
+        <Show
+            when=move || { state.target_dict.get().is_some() && state.open_export_dict.get() }
+            fallback=|| view! {}
+        >
+            <Dialog open=state.open_export_dict title="导出字典">
+                <ExportDictForm />
+            </Dialog>
+        </Show>
+        <Show
+            when=move || { state.target_dict.get().is_some() && state.open_import_dict.get() }
+            fallback=|| view! {}
+        >
+            <Dialog open=state.open_import_dict title="导入字典">
+                <ImportDictForm open=state.open_import_dict dict_resource />
+            </Dialog>
+        </Show>
+        <Show
+            when=move || { state.target_dict.get().is_some() && state.open_dict_codegen.get() }
+            fallback=|| view! {}
+        >
+            <Dialog open=state.open_dict_codegen title="生成Rust枚举">
+                <DictCodegenForm />
+            </Dialog>
+        </Show>
+        <Show
+            when=move || { state.target_dict.get().is_some() && state.open_dict_usage.get() }
+            fallback=|| view! {}
+        >
+            <Dialog open=state.open_dict_usage title="字典引用">
+                <DictUsagePanel
+                    dict_code=state.target_dict.get_untracked().unwrap().dict_code
+                    item_value=None
+                />
+            </Dialog>
+        </Show>
     }
 }
 
+/// 把`source`连同其下级移到`parent_code`下，`None`为顶级
+fn move_dict(
+    app_state: AppState,
+    dict_resource: WebResult<DictListItem>,
+    source: DictListItem,
+    parent_code: Option<String>,
+) {
+    let req = UpdateDictReqExt {
+        base: UpdateDictReq {
+            dict_code: source.dict_code,
+            dict_name: source.dict_name,
+            parent_code,
+            public: source.public,
+            remark: source.remark,
+            status: source.status,
+        },
+        labels: None,
+        ext_schema: None,
+    };
+    spawn_local(async move {
+        match async_update_dict(req).await {
+            Ok(_) => {
+                dict_resource.refetch();
+                app_state.success("移动字典成功")
+            }
+            Err(err) => app_state.error(err.to_string()),
+        }
+    });
+}
+
+fn sibling_codes(parent: &DictListItem) -> Vec<String> {
+    parent
+        .children
+        .iter()
+        .map(|dict| dict.dict_code.clone())
+        .collect()
+}
+
 #[component]
-fn DictLi(#[prop(into)] dict: Signal<DictListItem>) -> impl IntoView {
+fn DictLi(#[prop(into)] dict: Signal<DictListItem>, siblings: Vec<String>) -> impl IntoView {
+    let state = expect_context::<DictState>();
     let dict = dict.get_untracked();
+    let li_class = {
+        let dict = dict.clone();
+        move || {
+            let search = state.search.get();
+            if search.is_empty() || subtree_matches(&dict, &search) {
+                ""
+            } else {
+                "hidden"
+            }
+        }
+    };
     if dict.children.is_empty() {
         view! {
-            <li>
-                <Dict data=dict />
+            <li class=li_class>
+                <Dict data=dict siblings />
             </li>
         }
         .into_any()
     } else {
         view! {
-            <li>
-                <ParentDict dict />
+            <li class=li_class>
+                <ParentDict dict siblings />
             </li>
         }
         .into_any()
@@ -85,11 +245,36 @@ fn DictLi(#[prop(into)] dict: Signal<DictListItem>) -> impl IntoView {
 }
 
 #[component]
-fn ParentDict(#[prop(into)] dict: Signal<DictListItem>) -> impl IntoView {
+fn ParentDict(#[prop(into)] dict: Signal<DictListItem>, siblings: Vec<String>) -> impl IntoView {
+    let state = expect_context::<DictState>();
+    let children = sibling_codes(&dict.get_untracked());
+    let dict_code = dict.get_untracked().dict_code;
+    // 搜索时展开全部匹配路径，否则按记住的折叠状态
+    let open = {
+        let dict_code = dict_code.clone();
+        move || !state.search.get().is_empty() || !state.collapsed.get().contains(&dict_code)
+    };
     view! {
-        <details open>
+        <details
+            prop:open=open
+            on:toggle:target=move |ev| {
+                if !state.search.get_untracked().is_empty() {
+                    return;
+                }
+                let opened = ev.target().open();
+                state
+                    .collapsed
+                    .update(|collapsed| {
+                        if opened {
+                            collapsed.remove(&dict_code);
+                        } else {
+                            collapsed.insert(dict_code.clone());
+                        }
+                    });
+            }
+        >
             <summary>
-                <Dict data=dict.get_untracked() />
+                <Dict data=dict.get_untracked() siblings />
             </summary>
             <ul>
                 {dict
@@ -97,7 +282,7 @@ fn ParentDict(#[prop(into)] dict: Signal<DictListItem>) -> impl IntoView {
                     .children
                     .into_iter()
                     .map(|dict| {
-                        view! { <DictLi dict /> }
+                        view! { <DictLi dict siblings=children.clone() /> }
                     })
                     .collect_view()}
             </ul>
@@ -106,16 +291,88 @@ fn ParentDict(#[prop(into)] dict: Signal<DictListItem>) -> impl IntoView {
 }
 
 #[component]
-fn Dict(#[prop(into)] data: Signal<DictListItem>) -> impl IntoView {
+fn Dict(#[prop(into)] data: Signal<DictListItem>, siblings: Vec<String>) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
     let state = expect_context::<DictState>();
+    let dict_resource = expect_context::<WebResult<DictListItem>>();
+    // 拖放到同级字典上调整顺序；拖放到其他层级，或按住Alt拖放，则移为目标的下级
+    let on_drop = move |ev: leptos::ev::DragEvent| {
+        ev.prevent_default();
+        ev.stop_propagation();
+        let Some(source) = state.dragging_dict.get_untracked() else {
+            return;
+        };
+        state.dragging_dict.set(None);
+        let target = data.get_untracked();
+        if source.dict_code == target.dict_code {
+            return;
+        }
+        if ev.alt_key() || source.parent_code != target.parent_code {
+            if contains_dict(&source, &target.dict_code) {
+                app_state.error("不能把字典移到自身的下级".to_string());
+            } else if source.parent_code.as_deref() != Some(target.dict_code.as_str()) {
+                move_dict(app_state, dict_resource, source, Some(target.dict_code));
+            }
+            return;
+        }
+        let req = SortDictsReq {
+            parent_code: target.parent_code,
+            dict_codes: move_before(&siblings, &source.dict_code, &target.dict_code),
+        };
+        spawn_local(async move {
+            match async_sort_dicts(req).await {
+                Ok(_) => {
+                    dict_resource.refetch();
+                    app_state.success("排序已保存")
+                }
+                Err(err) => app_state.error(err.to_string()),
+            }
+        });
+    };
     view! {
         <span
             class="group"
+            draggable="true"
+            on:dragstart=move |ev| {
+                ev.stop_propagation();
+                state.dragging_dict.set(Some(data.get_untracked()));
+            }
+            on:dragend=move |_| state.dragging_dict.set(None)
+            on:dragover=move |ev| ev.prevent_default()
+            on:drop=on_drop
             on:click=move |_| {
                 state.current_dict.set(Some(data.get_untracked()));
             }
         >
-            {data.get_untracked().dict_name}
+            {move || {
+                let name = data.get_untracked().dict_name;
+                let code = data.get_untracked().dict_code;
+                let search = state.search.get();
+                match split_match(&name, &search) {
+                    Some((before, matched, after)) => {
+                        view! {
+                            {before}
+                            <mark>{matched}</mark>
+                            {after}
+                        }
+                            .into_any()
+                    }
+                    None => {
+                        match split_match(&code, &search) {
+                            Some((before, matched, after)) => {
+                                view! {
+                                    {name}
+                                    <span class="text-xs opacity-60">
+                                        {before}<mark>{matched}</mark>{after}
+                                    </span>
+                                }
+                                    .into_any()
+                            }
+                            None => view! { {name} }.into_any(),
+                        }
+                    }
+                }
+            }}
             <span class="hidden gap-2 justify-end group-hover:flex">
                 <button
                     class="btn btn-xs"
@@ -137,6 +394,56 @@ fn Dict(#[prop(into)] data: Signal<DictListItem>) -> impl IntoView {
                 >
                     修改
                 </button>
+                <button
+                    class="btn btn-xs"
+                    on:click=move |ev| {
+                        ev.stop_propagation();
+                        state.target_dict.set(Some(data.get_untracked()));
+                        state.open_export_dict.set(true);
+                    }
+                >
+                    导出
+                </button>
+                <button
+                    class="btn btn-xs"
+                    on:click=move |ev| {
+                        ev.stop_propagation();
+                        state.target_dict.set(Some(data.get_untracked()));
+                        state.open_import_dict.set(true);
+                    }
+                >
+                    导入
+                </button>
+                <button
+                    class="btn btn-xs"
+                    on:click=move |ev| {
+                        ev.stop_propagation();
+                        state.target_dict.set(Some(data.get_untracked()));
+                        state.open_dict_codegen.set(true);
+                    }
+                >
+                    代码
+                </button>
+                <button
+                    class="btn btn-xs"
+                    on:click=move |ev| {
+                        ev.stop_propagation();
+                        state.target_dict.set(Some(data.get_untracked()));
+                        state.open_dict_usage.set(true);
+                    }
+                >
+                    引用
+                </button>
+                <button
+                    class="btn btn-xs btn-error"
+                    on:click=move |ev| {
+                        ev.stop_propagation();
+                        state.target_dict.set(Some(data.get_untracked()));
+                        state.open_delete_dict.set(true);
+                    }
+                >
+                    删除
+                </button>
             </span>
         </span>
     }
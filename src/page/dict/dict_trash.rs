@@ -0,0 +1,149 @@
+use leptos::{prelude::*, task::spawn_local};
+use tower::{management_model::DictListItem, tauri_web::prelude::*};
+
+use crate::{
+    model::{DictItemListItemExt, RestoreDictItemReq, RestoreDictReq},
+    service::{
+        async_get_deleted_dict_items, async_get_deleted_dicts, async_restore_dict,
+        async_restore_dict_item,
+    },
+};
+
+#[component]
+pub(crate) fn DictTrash(dict_resource: WebResult<DictListItem>) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    let deleted_resource = LocalResource::new(move || async_get_deleted_dicts());
+    view! {
+        <table class="table">
+            <thead>
+                <tr>
+                    <th></th>
+                    <th>代码</th>
+                    <th>名称</th>
+                    <th>上级代码</th>
+                    <th>备注</th>
+                    <th>操作</th>
+                </tr>
+            </thead>
+            <tbody>
+                <Suspense fallback=move || {
+                    view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+                }>
+                    {move || {
+                        deleted_resource
+                            .to_view(|list| {
+                                list.into_iter()
+                                    .enumerate()
+                                    .map(|(index, dict)| {
+                                        let dict_code = dict.dict_code.clone();
+                                        view! {
+                                            <tr>
+                                                <th>{index + 1}</th>
+                                                <td>{dict.dict_code.clone()}</td>
+                                                <td>{dict.dict_name.clone()}</td>
+                                                <td>{dict.parent_code.clone()}</td>
+                                                <td>{dict.remark.clone()}</td>
+                                                <td>
+                                                    <button
+                                                        class="btn btn-xs"
+                                                        on:click=move |_| {
+                                                            let req = RestoreDictReq {
+                                                                dict_code: dict_code.clone(),
+                                                            };
+                                                            spawn_local(async move {
+                                                                match async_restore_dict(req).await {
+                                                                    Ok(_) => {
+                                                                        deleted_resource.refetch();
+                                                                        dict_resource.refetch();
+                                                                        app_state.success("恢复字典成功。")
+                                                                    }
+                                                                    Err(err) => app_state.error(err.to_string()),
+                                                                }
+                                                            });
+                                                        }
+                                                    >
+                                                        恢复
+                                                    </button>
+                                                </td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view()
+                                    .into_any()
+                            })
+                    }}
+                </Suspense>
+            </tbody>
+        </table>
+    }
+}
+
+#[component]
+pub(crate) fn DictItemTrash(
+    dict_code: String,
+    item_resource: WebResult<Vec<DictItemListItemExt>>,
+) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    let deleted_resource =
+        LocalResource::new(move || async_get_deleted_dict_items(Some(dict_code.clone())));
+    view! {
+        <table class="table">
+            <thead>
+                <tr>
+                    <th></th>
+                    <th>名称</th>
+                    <th>值</th>
+                    <th>备注</th>
+                    <th>操作</th>
+                </tr>
+            </thead>
+            <tbody>
+                <Suspense fallback=move || {
+                    view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+                }>
+                    {move || {
+                        deleted_resource
+                            .to_view(|list| {
+                                list.into_iter()
+                                    .enumerate()
+                                    .map(|(index, item)| {
+                                        let id = item.id;
+                                        view! {
+                                            <tr>
+                                                <th>{index + 1}</th>
+                                                <td>{item.item_name.clone()}</td>
+                                                <td>{item.item_value.clone()}</td>
+                                                <td>{item.remark.clone()}</td>
+                                                <td>
+                                                    <button
+                                                        class="btn btn-xs"
+                                                        on:click=move |_| {
+                                                            spawn_local(async move {
+                                                                match async_restore_dict_item(RestoreDictItemReq { id })
+                                                                    .await
+                                                                {
+                                                                    Ok(_) => {
+                                                                        deleted_resource.refetch();
+                                                                        item_resource.refetch();
+                                                                        app_state.success("恢复字典项成功。")
+                                                                    }
+                                                                    Err(err) => app_state.error(err.to_string()),
+                                                                }
+                                                            });
+                                                        }
+                                                    >
+                                                        恢复
+                                                    </button>
+                                                </td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view()
+                                    .into_any()
+                            })
+                    }}
+                </Suspense>
+            </tbody>
+        </table>
+    }
+}
@@ -0,0 +1,102 @@
+use tower::management_model::DictListItem;
+
+/// `dict`或其下级中是否包含`dict_code`，用于阻止把字典移到自己的下级
+pub(crate) fn contains_dict(dict: &DictListItem, dict_code: &str) -> bool {
+    dict.dict_code == dict_code
+        || dict
+            .children
+            .iter()
+            .any(|child| contains_dict(child, dict_code))
+}
+
+/// 按树的顺序展开为`(层级, 字典)`，跳过`exclude`及其下级
+pub(crate) fn flatten_dicts(root: &DictListItem, exclude: &str) -> Vec<(usize, DictListItem)> {
+    fn walk(
+        dict: &DictListItem,
+        depth: usize,
+        exclude: &str,
+        result: &mut Vec<(usize, DictListItem)>,
+    ) {
+        for child in dict.children.iter() {
+            if child.dict_code == exclude {
+                continue;
+            }
+            result.push((depth, child.clone()));
+            walk(child, depth + 1, exclude, result);
+        }
+    }
+    let mut result = vec![];
+    walk(root, 0, exclude, &mut result);
+    result
+}
+
+/// 代码或名称包含关键字（忽略大小写）
+pub(crate) fn dict_matches(dict: &DictListItem, keyword: &str) -> bool {
+    let keyword = keyword.to_ascii_lowercase();
+    dict.dict_code.to_ascii_lowercase().contains(&keyword)
+        || dict.dict_name.to_ascii_lowercase().contains(&keyword)
+}
+
+/// 自身或任一下级匹配关键字，用于过滤节点并展开匹配项的上级
+pub(crate) fn subtree_matches(dict: &DictListItem, keyword: &str) -> bool {
+    dict_matches(dict, keyword)
+        || dict
+            .children
+            .iter()
+            .any(|child| subtree_matches(child, keyword))
+}
+
+/// 有下级的字典代码，即可以折叠的节点
+pub(crate) fn parent_codes(root: &DictListItem) -> Vec<String> {
+    flatten_dicts(root, "")
+        .into_iter()
+        .filter(|(_, dict)| !dict.children.is_empty())
+        .map(|(_, dict)| dict.dict_code)
+        .collect()
+}
+
+/// 按关键字拆分为`(前段, 匹配, 后段)`，不匹配时返回`None`
+pub(crate) fn split_match(text: &str, keyword: &str) -> Option<(String, String, String)> {
+    if keyword.is_empty() {
+        return None;
+    }
+    let start = text
+        .to_ascii_lowercase()
+        .find(&keyword.to_ascii_lowercase())?;
+    let end = start + keyword.len();
+    Some((
+        text[..start].to_string(),
+        text[start..end].to_string(),
+        text[end..].to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parts(before: &str, matched: &str, after: &str) -> Option<(String, String, String)> {
+        Some((before.to_string(), matched.to_string(), after.to_string()))
+    }
+
+    #[test]
+    fn split_match_ignores_ascii_case() {
+        assert_eq!(split_match("VipLevel", "level"), parts("Vip", "Level", ""));
+        assert_eq!(split_match("vip_level", "VIP"), parts("", "vip", "_level"));
+    }
+
+    #[test]
+    fn split_match_keeps_multibyte_boundaries() {
+        assert_eq!(
+            split_match("会员等级Level", "等级"),
+            parts("会员", "等级", "Level")
+        );
+        assert_eq!(split_match("会员Level", "lev"), parts("会员", "Lev", "el"));
+    }
+
+    #[test]
+    fn split_match_without_match() {
+        assert_eq!(split_match("会员等级", ""), None);
+        assert_eq!(split_match("会员等级", "地区"), None);
+    }
+}
@@ -0,0 +1,150 @@
+use leptos::{prelude::*, task::spawn_local};
+use tower::tauri_web::prelude::*;
+
+use crate::{
+    model::{AddDictDependencyReq, DictUsage, DictUsageReq},
+    page::component::AppSelect,
+    service::{async_add_dict_dependency, async_delete_dict_dependency, async_get_dict_usage},
+};
+
+/// 字典或字典项的引用；查询整个字典时可以登记、移除应用依赖
+#[component]
+pub(crate) fn DictUsagePanel(dict_code: String, item_value: Option<String>) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    let is_dict = item_value.is_none();
+    let usage_resource = LocalResource::new({
+        let dict_code = dict_code.clone();
+        move || {
+            async_get_dict_usage(DictUsageReq {
+                dict_code: dict_code.clone(),
+                item_value: item_value.clone(),
+            })
+        }
+    });
+    let app = RwSignal::new(App::TowerServer);
+    view! {
+        <Suspense fallback=move || {
+            view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+        }>
+            {move || {
+                usage_resource
+                    .to_view(move |usage| {
+                        view! {
+                            <p class="my-2">{usage.summary()}</p>
+                            <h3 class="mt-4 font-bold">配置</h3>
+                            <table class="table table-xs">
+                                <thead>
+                                    <tr>
+                                        <th>应用</th>
+                                        <th>变量名</th>
+                                        <th>变量值</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {usage
+                                        .configs
+                                        .into_iter()
+                                        .map(|config| {
+                                            view! {
+                                                <tr>
+                                                    <td>{config.app.label()}</td>
+                                                    <td>{config.var_name}</td>
+                                                    <td>{config.var_value}</td>
+                                                </tr>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </tbody>
+                            </table>
+                            <h3 class="mt-4 font-bold">字典</h3>
+                            <p>{usage.dicts.join("，")}</p>
+                            <h3 class="mt-4 font-bold">依赖的应用</h3>
+                            <ul class="list">
+                                {usage
+                                    .apps
+                                    .into_iter()
+                                    .map(|dependency| {
+                                        let id = dependency.id;
+                                        view! {
+                                            <li class="list-row">
+                                                <span>{dependency.app.label()}</span>
+                                                <span>{from_secs_and_default(dependency.created)}</span>
+                                                {is_dict
+                                                    .then(|| {
+                                                        view! {
+                                                    <button
+                                                        class="btn btn-xs"
+                                                        on:click=move |_| {
+                                                            spawn_local(async move {
+                                                                match async_delete_dict_dependency(id).await {
+                                                                    Ok(_) => {
+                                                                        usage_resource.refetch();
+                                                                        app_state.success("移除成功")
+                                                                    }
+                                                                    Err(err) => app_state.error(err.to_string()),
+                                                                }
+                                                            });
+                                                        }
+                                                    >
+                                                        移除
+                                                    </button>
+                                                        }
+                                                    })}
+                                            </li>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </ul>
+                        }
+                            .into_any()
+                    })
+            }}
+        </Suspense>
+        <Show when=move || is_dict>
+            <div class="flex gap-2 mt-4">
+                <AppSelect selected=app />
+                <button
+                    class="btn"
+                    on:click={
+                        let dict_code = dict_code.clone();
+                        move |_| {
+                            let req = AddDictDependencyReq {
+                                dict_code: dict_code.clone(),
+                                app: app.get_untracked(),
+                            };
+                            spawn_local(async move {
+                                match async_add_dict_dependency(req).await {
+                                    Ok(_) => {
+                                        usage_resource.refetch();
+                                        app_state.success("登记成功")
+                                    }
+                                    Err(err) => app_state.error(err.to_string()),
+                                }
+                            });
+                        }
+                    }
+                >
+                    登记依赖
+                </button>
+            </div>
+        </Show>
+    }
+}
+
+/// 删除、停用前的引用提示，有引用时需要勾选确认
+#[component]
+pub(crate) fn DictUsageWarning(usage: DictUsage, confirmed: RwSignal<bool>) -> impl IntoView {
+    if usage.is_empty() {
+        return view! {}.into_any();
+    }
+    view! {
+        <div class="mt-2 alert alert-warning">
+            {format!("{}，客户端下拉框可能受影响。", usage.summary())}
+        </div>
+        <label class="label">
+            <input type="checkbox" class="checkbox" bind:checked=confirmed />
+            "仍然继续"
+        </label>
+    }
+    .into_any()
+}
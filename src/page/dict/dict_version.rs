@@ -0,0 +1,269 @@
+use leptos::{prelude::*, task::spawn_local};
+use tower::tauri_web::prelude::*;
+
+use crate::{
+    model::{
+        DictItemDiff, DictItemListItemExt, DictTransferItem, DictVersionListReq, DictVersionReq,
+        PublishDictReq, RollbackDictReq,
+    },
+    service::{
+        async_get_dict_items, async_get_dict_version, async_get_dict_versions, async_publish_dict,
+        async_rollback_dict,
+    },
+};
+
+/// 草稿与最新版本的差异、发布，以及历史版本的查看和回滚
+#[component]
+pub(crate) fn DictVersionPanel(
+    dict_code: String,
+    item_resource: WebResult<Vec<DictItemListItemExt>>,
+) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    let versions_resource = LocalResource::new({
+        let dict_code = dict_code.clone();
+        move || {
+            let dict_code = dict_code.clone();
+            async move {
+                let mut versions = async_get_dict_versions(DictVersionListReq {
+                    dict_code: dict_code.clone(),
+                })
+                .await?;
+                versions.sort_by_key(|version| std::cmp::Reverse(version.version));
+                let published = match versions.first() {
+                    Some(latest) => {
+                        async_get_dict_version(DictVersionReq {
+                            dict_code: dict_code.clone(),
+                            version: latest.version,
+                        })
+                        .await?
+                        .items
+                    }
+                    None => vec![],
+                };
+                let draft =
+                    DictTransferItem::from_items(async_get_dict_items(Some(dict_code)).await?);
+                Ok((versions, DictItemDiff::diff(&published, &draft)))
+            }
+        }
+    });
+    let viewing = RwSignal::new(None::<i32>);
+    let version_resource = LocalResource::new({
+        let dict_code = dict_code.clone();
+        move || {
+            let req = viewing.get().map(|version| DictVersionReq {
+                dict_code: dict_code.clone(),
+                version,
+            });
+            async move {
+                match req {
+                    Some(req) => async_get_dict_version(req).await.map(|detail| detail.items),
+                    None => Ok(vec![]),
+                }
+            }
+        }
+    });
+    let remark = RwSignal::new(String::new());
+    let op_tiper = OpTiper::new();
+    let publish = {
+        let dict_code = dict_code.clone();
+        move |_| {
+            let req = PublishDictReq {
+                dict_code: dict_code.clone(),
+                remark: str_trim_to_option(remark.get_untracked()),
+            };
+            spawn_local(async move {
+                let resp = async_publish_dict(req).await;
+                tip_or(resp, op_tiper.0, |_| {
+                    remark.set(String::new());
+                    versions_resource.refetch();
+                    app_state.success("发布成功。");
+                });
+            });
+        }
+    };
+    view! {
+        <Suspense fallback=move || {
+            view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+        }>
+            {move || {
+                versions_resource
+                    .to_view(move |(versions, diffs)| {
+                        let unchanged = diffs.is_empty();
+                        let latest = versions.first().map(|version| version.version);
+                        view! {
+                            <h3 class="font-bold">
+                                {match latest {
+                                    Some(version) => format!("草稿与 v{} 的差异", version),
+                                    None => "尚未发布".to_string(),
+                                }}
+                            </h3>
+                            {if unchanged {
+                                view! { <p class="my-2">"草稿没有未发布的修改"</p> }.into_any()
+                            } else {
+                                view! { <DictItemDiffTable diffs /> }.into_any()
+                            }}
+                            <h3 class="mt-4 font-bold">历史版本</h3>
+                            <table class="table table-xs">
+                                <thead>
+                                    <tr>
+                                        <th>版本</th>
+                                        <th>说明</th>
+                                        <th>发布人</th>
+                                        <th>发布时间</th>
+                                        <th>操作</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {versions
+                                        .into_iter()
+                                        .map(|version| {
+                                            let number = version.version;
+                                            let dict_code = version.dict_code.clone();
+                                            view! {
+                                                <tr>
+                                                    <td>{format!("v{}", number)}</td>
+                                                    <td>{version.remark.clone()}</td>
+                                                    <td>{version.publisher.clone()}</td>
+                                                    <td>{from_secs_and_default(version.published)}</td>
+                                                    <td>
+                                                        <button
+                                                            class="btn btn-xs"
+                                                            on:click=move |_| viewing.set(Some(number))
+                                                        >
+                                                            查看
+                                                        </button>
+                                                        {(latest != Some(number))
+                                                            .then(|| {
+                                                                view! {
+                                                                    <ConfirmDialogButton
+                                                                        btn="回滚"
+                                                                        btn_class="btn-xs"
+                                                                        title="回滚字典"
+                                                                        message=format!(
+                                                                            "草稿将恢复为 v{} 的内容并发布为新版本，确认回滚？",
+                                                                            number,
+                                                                        )
+                                                                        on_ok=move || {
+                                                                            let req = RollbackDictReq {
+                                                                                dict_code: dict_code.clone(),
+                                                                                version: number,
+                                                                            };
+                                                                            spawn_local(async move {
+                                                                                match async_rollback_dict(req).await {
+                                                                                    Ok(_) => {
+                                                                                        versions_resource.refetch();
+                                                                                        item_resource.refetch();
+                                                                                        app_state.success("回滚成功")
+                                                                                    }
+                                                                                    Err(err) => app_state.error(err.to_string()),
+                                                                                }
+                                                                            });
+                                                                        }
+                                                                    />
+                                                                }
+                                                            })}
+                                                    </td>
+                                                </tr>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </tbody>
+                            </table>
+                            <fieldset class="mt-4 w-full fieldset">
+                                <label class="label">发布说明</label>
+                                <input type="text" class="w-full input" maxlength="200" bind:value=remark />
+                                <OpTip content=op_tiper.0 />
+                                <button
+                                    class="mt-2 btn btn-neutral"
+                                    type="button"
+                                    disabled=unchanged
+                                    on:click=publish.clone()
+                                >
+                                    发布
+                                </button>
+                            </fieldset>
+                        }
+                            .into_any()
+                    })
+            }}
+        </Suspense>
+        <Show when=move || viewing.get().is_some()>
+            <h3 class="mt-4 font-bold">
+                {move || format!("v{} 的字典项", viewing.get().unwrap_or_default())}
+            </h3>
+            <Suspense fallback=move || {
+                view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+            }>
+                {move || {
+                    version_resource
+                        .to_view(|items| {
+                            view! {
+                                <table class="table table-xs">
+                                    <thead>
+                                        <tr>
+                                            <th>名称</th>
+                                            <th>值</th>
+                                            <th>上级</th>
+                                            <th>状态</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        {items
+                                            .into_iter()
+                                            .map(|item| {
+                                                view! {
+                                                    <tr>
+                                                        <td>{item.item_name}</td>
+                                                        <td>{item.item_value}</td>
+                                                        <td>{item.parent_value}</td>
+                                                        <td>{item.status.label()}</td>
+                                                    </tr>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </tbody>
+                                </table>
+                            }
+                                .into_any()
+                        })
+                }}
+            </Suspense>
+        </Show>
+    }
+}
+
+#[component]
+fn DictItemDiffTable(diffs: Vec<DictItemDiff>) -> impl IntoView {
+    let describe = |item: &Option<DictTransferItem>| {
+        item.as_ref()
+            .map(|item| format!("{}（{}）", item.item_name, item.status.label()))
+            .unwrap_or_default()
+    };
+    view! {
+        <table class="table table-xs">
+            <thead>
+                <tr>
+                    <th>变更</th>
+                    <th>值</th>
+                    <th>发布版本</th>
+                    <th>草稿</th>
+                </tr>
+            </thead>
+            <tbody>
+                {diffs
+                    .into_iter()
+                    .map(|diff| {
+                        view! {
+                            <tr>
+                                <td>{diff.label()}</td>
+                                <td>{diff.item_value.clone()}</td>
+                                <td>{describe(&diff.before)}</td>
+                                <td>{describe(&diff.after)}</td>
+                            </tr>
+                        }
+                    })
+                    .collect_view()}
+            </tbody>
+        </table>
+    }
+}
@@ -1,7 +1,14 @@
+use std::collections::BTreeSet;
+
 use super::*;
+use codee::string::FromToStringCodec;
 use leptos::prelude::*;
+use leptos_use::{signal_debounced, storage::use_local_storage};
 use tower::management_model::DictListItem;
 
+/// 本地存储中折叠的字典代码，逗号分隔
+const COLLAPSED_DICTS_KEY: &str = "dict_menu_collapsed";
+
 #[component]
 pub fn DictPane() -> impl IntoView {
     let state = DictState::new();
@@ -22,14 +29,49 @@ pub(crate) struct DictState {
     pub open_add_dict: RwSignal<bool>,
     pub open_update_dict: RwSignal<bool>,
     pub target_dict: RwSignal<Option<DictListItem>>,
+    pub open_delete_dict: RwSignal<bool>,
+    pub open_dict_trash: RwSignal<bool>,
+    pub open_export_dict: RwSignal<bool>,
+    pub open_import_dict: RwSignal<bool>,
+    pub open_dict_codegen: RwSignal<bool>,
+    pub open_dict_usage: RwSignal<bool>,
+    pub dragging_dict: RwSignal<Option<DictListItem>>,
+    pub keyword: RwSignal<String>,
+    /// 防抖后的搜索关键字
+    pub search: Signal<String>,
+    pub collapsed: RwSignal<BTreeSet<String>>,
 }
 impl DictState {
     pub fn new() -> Self {
+        let keyword = RwSignal::new(String::new());
+        let (stored, set_stored, _) =
+            use_local_storage::<String, FromToStringCodec>(COLLAPSED_DICTS_KEY);
+        let collapsed = RwSignal::new(
+            stored
+                .get_untracked()
+                .split(',')
+                .filter(|code| !code.is_empty())
+                .map(String::from)
+                .collect::<BTreeSet<_>>(),
+        );
+        Effect::new(move |_| {
+            set_stored.set(collapsed.get().into_iter().collect::<Vec<_>>().join(","));
+        });
         Self {
             current_dict: RwSignal::new(None),
             open_add_dict: RwSignal::new(false),
             open_update_dict: RwSignal::new(false),
             target_dict: RwSignal::new(None),
+            open_delete_dict: RwSignal::new(false),
+            open_dict_trash: RwSignal::new(false),
+            open_export_dict: RwSignal::new(false),
+            open_import_dict: RwSignal::new(false),
+            open_dict_codegen: RwSignal::new(false),
+            open_dict_usage: RwSignal::new(false),
+            dragging_dict: RwSignal::new(None),
+            keyword,
+            search: signal_debounced(keyword, 300.0),
+            collapsed,
         }
     }
 }
@@ -1,12 +1,21 @@
 use crate::{
-    page::{AddItemForm, DictState, UpdateItemForm},
-    service::async_get_dict_items,
+    model::{
+        dict_item_rows, missing_locales, AuditResourceType, DictItemRow, SortDictItemsReq, LOCALES,
+    },
+    page::{
+        move_before, ActivityPane, AddItemForm, BulkAddItemForm, DeleteItemForm, DictItemBadge,
+        DictItemTrash, DictItemValidityBadge, DictState, DictUsagePanel, DictVersionPanel,
+        UpdateItemForm,
+    },
+    service::{async_get_dict_items, async_sort_dict_items},
 };
-use leptos::prelude::*;
+use leptos::{prelude::*, task::spawn_local};
+use std::collections::HashSet;
 use tower::tauri_web::prelude::*;
 
 #[component]
 pub fn ItemTable() -> impl IntoView {
+    let app_state = expect_context::<AppState>();
     let state = expect_context::<DictState>();
     let item_resource = LocalResource::new(move || {
         let dict_code = state.current_dict.get().map(|dict| dict.dict_code);
@@ -14,25 +23,91 @@ pub fn ItemTable() -> impl IntoView {
     });
     let open_add = RwSignal::new(false);
     let open_update = RwSignal::new(false);
+    let open_delete = RwSignal::new(false);
+    let open_usage = RwSignal::new(false);
     let current_item = RwSignal::new(None);
+    let open_trash = RwSignal::new(false);
+    let open_version = RwSignal::new(false);
+    let open_bulk_add = RwSignal::new(false);
+    let dragging_item = RwSignal::new(None::<i32>);
+    let missing_only = RwSignal::new(false);
+    let add_parent = RwSignal::new(None::<i32>);
+    let collapsed = RwSignal::new(HashSet::<i32>::new());
+    let drop_item = move |ids: Vec<i32>, target: i32| {
+        let Some(source) = dragging_item.get_untracked() else {
+            return;
+        };
+        dragging_item.set(None);
+        if source == target {
+            return;
+        }
+        let req = SortDictItemsReq {
+            dict_code: state.current_dict.get_untracked().unwrap().dict_code,
+            ids: move_before(&ids, &source, &target),
+        };
+        spawn_local(async move {
+            match async_sort_dict_items(req).await {
+                Ok(_) => {
+                    item_resource.refetch();
+                    app_state.success("排序已保存")
+                }
+                Err(err) => app_state.error(err.to_string()),
+            }
+        });
+    };
     view! {
         <div class="flex flex-col gap-4">
             <div class="flex justify-between items-center">
-                <span>{move || state.current_dict.get().unwrap().dict_name}</span>
-                <button
-                    class="btn btn-sm"
-                    on:click=move |_| {
-                        open_add.set(true);
-                    }
-                >
-                    新增
-                </button>
+                <span>
+                    {move || state.current_dict.get().unwrap().dict_name}
+                    <span class="ml-2 text-xs opacity-60">拖拽行调整排序</span>
+                </span>
+                <span class="flex gap-2 items-center">
+                    <label class="label">
+                        <input type="checkbox" class="checkbox" bind:checked=missing_only />
+                        缺少翻译
+                    </label>
+                    <button
+                        class="btn btn-sm"
+                        on:click=move |_| {
+                            add_parent.set(None);
+                            open_add.set(true);
+                        }
+                    >
+                        新增
+                    </button>
+                    <button
+                        class="btn btn-sm"
+                        on:click=move |_| {
+                            open_bulk_add.set(true);
+                        }
+                    >
+                        批量新增
+                    </button>
+                    <button
+                        class="btn btn-sm"
+                        on:click=move |_| {
+                            open_trash.set(true);
+                        }
+                    >
+                        回收站
+                    </button>
+                    <button
+                        class="btn btn-sm"
+                        on:click=move |_| {
+                            open_version.set(true);
+                        }
+                    >
+                        版本
+                    </button>
+                </span>
             </div>
             <table class="table">
                 <thead>
                     <tr>
                         <th></th>
                         <th>名称</th>
+                        <th>翻译</th>
                         <th>值</th>
                         <th>是否公开</th>
                         <th>备注</th>
@@ -50,19 +125,111 @@ pub fn ItemTable() -> impl IntoView {
                     }>
                         {move || {
                             item_resource
-                                .to_view(|list| {
-                                    list.into_iter()
+                                .to_view(move |list| {
+                                    let ids: Vec<i32> = list.iter().map(|item| item.id).collect();
+                                    dict_item_rows(&list)
+                                        .into_iter()
+                                        .filter(|row| {
+                                            !missing_only.get()
+                                                || !missing_locales(&row.item.labels).is_empty()
+                                        })
                                         .enumerate()
-                                        .map(|(index, item)| {
-                                            let item = item.clone();
+                                        .map(|(index, row)| {
+                                            let DictItemRow { depth, ancestors, has_children, item } = row;
+                                            let id = item.id;
+                                            let ids = ids.clone();
+                                            let row_class = move || {
+                                                let mut class = "cursor-move".to_string();
+                                                if dragging_item.get() == Some(id) {
+                                                    class.push_str(" opacity-50");
+                                                }
+                                                if collapsed
+                                                    .with(|collapsed| {
+                                                        ancestors.iter().any(|id| collapsed.contains(id))
+                                                    })
+                                                {
+                                                    class.push_str(" hidden");
+                                                }
+                                                class
+                                            };
                                             view! {
-                                                <tr>
+                                                <tr
+                                                    class=row_class
+                                                    draggable="true"
+                                                    on:dragstart=move |_| dragging_item.set(Some(id))
+                                                    on:dragend=move |_| dragging_item.set(None)
+                                                    on:dragover=move |ev| ev.prevent_default()
+                                                    on:drop=move |ev| {
+                                                        ev.prevent_default();
+                                                        drop_item(ids.clone(), id);
+                                                    }
+                                                >
                                                     <th>{index + 1}</th>
-                                                    <td>{item.item_name.clone()}</td>
+                                                    <td style=format!("padding-left: {}rem", 1 + depth * 2)>
+                                                        {has_children
+                                                            .then(|| {
+                                                                view! {
+                                                                    <button
+                                                                        class="btn btn-ghost btn-xs"
+                                                                        on:click=move |_| {
+                                                                            collapsed
+                                                                                .update(|collapsed| {
+                                                                                    if !collapsed.remove(&id) {
+                                                                                        collapsed.insert(id);
+                                                                                    }
+                                                                                })
+                                                                        }
+                                                                    >
+                                                                        {move || {
+                                                                            if collapsed.get().contains(&id) { "▸" } else { "▾" }
+                                                                        }}
+                                                                    </button>
+                                                                }
+                                                            })}
+                                                        {if item.color.is_some() || item.icon.is_some() {
+                                                            view! {
+                                                                <DictItemBadge
+                                                                    name=item.item_name.clone()
+                                                                    color=item.color.clone()
+                                                                    icon=item.icon.clone()
+                                                                />
+                                                            }
+                                                                .into_any()
+                                                        } else {
+                                                            item.item_name.clone().into_any()
+                                                        }}
+                                                    </td>
+                                                    <td>
+                                                        {LOCALES
+                                                            .iter()
+                                                            .map(|(locale, locale_name)| {
+                                                                let class = if item
+                                                                    .labels
+                                                                    .get(*locale)
+                                                                    .is_some_and(|label| !label.trim().is_empty())
+                                                                {
+                                                                    "badge badge-sm badge-success"
+                                                                } else {
+                                                                    "badge badge-sm badge-warning"
+                                                                };
+                                                                view! {
+                                                                    <span
+                                                                        class=class
+                                                                        title=item.labels.get(*locale).cloned()
+                                                                    >
+                                                                        {*locale_name}
+                                                                    </span>
+                                                                }
+                                                            })
+                                                            .collect_view()}
+                                                    </td>
                                                     <td>{item.item_value.clone()}</td>
                                                     <td>{item.public.label()}</td>
                                                     <td>{item.remark.clone()}</td>
-                                                    <td>{item.status.label()}</td>
+                                                    <td>
+                                                        {item.status.label()}
+                                                        <DictItemValidityBadge item=item.clone() />
+                                                    </td>
                                                     <td>{from_secs_and_default(item.created)}</td>
                                                     <td>
                                                         {item
@@ -75,13 +242,49 @@ pub fn ItemTable() -> impl IntoView {
                                                     <td>
                                                         <button
                                                             class="btn btn-xs"
-                                                            on:click=move |_| {
-                                                                current_item.set(Some(item.clone()));
-                                                                open_update.set(true)
+                                                            on:click={
+                                                                let item = item.clone();
+                                                                move |_| {
+                                                                    current_item.set(Some(item.clone()));
+                                                                    open_update.set(true)
+                                                                }
                                                             }
                                                         >
                                                             修改
                                                         </button>
+                                                        <button
+                                                            class="btn btn-xs"
+                                                            on:click=move |_| {
+                                                                add_parent.set(Some(id));
+                                                                open_add.set(true)
+                                                            }
+                                                        >
+                                                            新增子项
+                                                        </button>
+                                                        <button
+                                                            class="btn btn-xs"
+                                                            on:click={
+                                                                let item = item.clone();
+                                                                move |_| {
+                                                                    current_item.set(Some(item.clone()));
+                                                                    open_usage.set(true)
+                                                                }
+                                                            }
+                                                        >
+                                                            引用
+                                                        </button>
+                                                        <button
+                                                            class="btn btn-xs"
+                                                            on:click={
+                                                                let item = item.clone();
+                                                                move |_| {
+                                                                    current_item.set(Some(item.clone()));
+                                                                    open_delete.set(true)
+                                                                }
+                                                            }
+                                                        >
+                                                            删除
+                                                        </button>
                                                     </td>
                                                 </tr>
                                             }
@@ -93,10 +296,19 @@ pub fn ItemTable() -> impl IntoView {
                     </Suspense>
                 </tbody>
             </table>
+            <div>
+                <ActivityPane
+                    resource_types=vec![AuditResourceType::Dict, AuditResourceType::DictDependency]
+                    child_types=vec![AuditResourceType::DictItem]
+                    target_id=Signal::derive(move || {
+                        state.current_dict.get().map(|dict| dict.dict_code)
+                    })
+                />
+            </div>
         </div>
         <Show when=move || { open_add.get() } fallback=|| view! {}>
             <Dialog open=open_add title="新增字典项">
-                <AddItemForm open=open_add item_resource />
+                <AddItemForm open=open_add item_resource parent_id=add_parent.get_untracked() />
             </Dialog>
         </Show>
         <Show
@@ -107,5 +319,53 @@ pub fn ItemTable() -> impl IntoView {
                 <UpdateItemForm open=open_update item_resource item=current_item />
             </Dialog>
         </Show>
+        <Show
+            when=move || { current_item.get().is_some() && open_delete.get() }
+            fallback=|| view! {}
+        >
+            <Dialog open=open_delete title="删除字典项">
+                <DeleteItemForm open=open_delete item_resource item=current_item />
+            </Dialog>
+        </Show>
+        <Show
+            when=move || { current_item.get().is_some() && open_usage.get() }
+            fallback=|| view! {}
+        >
+            <Dialog open=open_usage title="字典项引用">
+                {move || {
+                    current_item
+                        .get()
+                        .map(|item| {
+                            view! {
+                                <DictUsagePanel
+                                    dict_code=item.dict_code.clone()
+                                    item_value=Some(item.item_value.clone())
+                                />
+                            }
+                        })
+                }}
+            </Dialog>
+        </Show>
+        <Show when=move || { open_trash.get() } fallback=|| view! {}>
+            <Dialog open=open_trash title="字典项回收站">
+                <DictItemTrash
+                    dict_code=state.current_dict.get_untracked().unwrap().dict_code
+                    item_resource
+                />
+            </Dialog>
+        </Show>
+        <Show when=move || { open_bulk_add.get() } fallback=|| view! {}>
+            <Dialog open=open_bulk_add title="批量新增字典项">
+                <BulkAddItemForm item_resource />
+            </Dialog>
+        </Show>
+        <Show when=move || { open_version.get() } fallback=|| view! {}>
+            <Dialog open=open_version title="字典版本">
+                <DictVersionPanel
+                    dict_code=state.current_dict.get_untracked().unwrap().dict_code
+                    item_resource
+                />
+            </Dialog>
+        </Show>
     }
 }
@@ -0,0 +1,49 @@
+use leptos::prelude::*;
+use tower::tauri_web::prelude::*;
+
+use crate::model::{DictItemListItemExt, DictItemValidity};
+
+/// 字典项的生效、失效时间，值为`<input type="datetime-local">`的格式，为空表示不限
+#[component]
+pub(crate) fn DictItemValidityFields(
+    valid_from: RwSignal<String>,
+    valid_until: RwSignal<String>,
+) -> impl IntoView {
+    view! {
+        <label class="label">有效期</label>
+        <div class="flex gap-2 items-center">
+            <input type="datetime-local" class="input" bind:value=valid_from />
+            <span>至</span>
+            <input type="datetime-local" class="input" bind:value=valid_until />
+        </div>
+        <p class="label">"为空表示不限；有效期外的字典项不会返回给客户端"</p>
+    }
+}
+
+/// 有效期外的字典项显示未生效或已过期
+#[component]
+pub(crate) fn DictItemValidityBadge(item: DictItemListItemExt) -> impl IntoView {
+    let now_secs = (js_sys::Date::now() / 1000.0) as i64;
+    let title = format!(
+        "{} 至 {}",
+        item.valid_from
+            .map(from_secs_and_default)
+            .unwrap_or_else(|| "不限".to_string()),
+        item.valid_until
+            .map(from_secs_and_default)
+            .unwrap_or_else(|| "不限".to_string()),
+    );
+    match item.validity(now_secs) {
+        DictItemValidity::Active => None,
+        validity @ DictItemValidity::Scheduled => Some(view! {
+            <span class="ml-1 badge badge-sm badge-info" title=title>
+                {validity.label()}
+            </span>
+        }),
+        validity @ DictItemValidity::Expired => Some(view! {
+            <span class="ml-1 badge badge-sm badge-ghost" title=title>
+                {validity.label()}
+            </span>
+        }),
+    }
+}
@@ -18,3 +18,36 @@ pub use update_dict::*;
 
 mod update_item;
 pub use update_item::*;
+
+mod delete_dict;
+pub use delete_dict::*;
+
+mod delete_item;
+pub use delete_item::*;
+
+mod dict_trash;
+pub use dict_trash::*;
+
+mod transfer_dict;
+pub use transfer_dict::*;
+
+mod dict_codegen;
+pub use dict_codegen::*;
+
+mod dict_tree;
+pub(crate) use dict_tree::*;
+
+mod dict_usage;
+pub use dict_usage::*;
+
+mod dict_version;
+pub use dict_version::*;
+
+mod dict_ext;
+pub(crate) use dict_ext::*;
+
+mod bulk_add_item;
+pub use bulk_add_item::*;
+
+mod item_validity;
+pub(crate) use item_validity::*;
@@ -0,0 +1,418 @@
+use std::collections::{HashMap, HashSet};
+
+use leptos::{prelude::*, task::spawn_local};
+use tower::{
+    management_model::{
+        AddDictItemReq, AddDictReq, DictListItem, UpdateDictItemReq, UpdateDictReq,
+    },
+    tauri_web::prelude::*,
+    web_model::{OpTipData, OpTipType},
+};
+
+use crate::{
+    model::{
+        check_import_parents, dicts_from_csv, dicts_to_csv, keep_current_flags, AddDictItemReqExt,
+        AddDictReqExt, DictChangeKind, DictImportPlan, DictTransferDict, DictTransferFormat,
+        UpdateDictItemReqExt, UpdateDictReqExt,
+    },
+    page::{contains_dict, DictState},
+    service::{
+        async_add_dict, async_add_dict_item, async_get_dict_items, async_get_dict_subtree,
+        async_get_dicts, async_update_dict, async_update_dict_item,
+    },
+};
+
+#[component]
+pub(crate) fn ExportDictForm() -> impl IntoView {
+    let state = expect_context::<DictState>();
+    let dict = state.target_dict.get_untracked().unwrap();
+    let format = RwSignal::new(DictTransferFormat::Json);
+    let download = RwSignal::new(None::<String>);
+    let op_tiper = OpTiper::new();
+    let file_name = {
+        let dict_code = dict.dict_code.clone();
+        move || format.get().file_name(&dict_code)
+    };
+    view! {
+        <form on:submit=move |ev| {
+            ev.prevent_default();
+            let dict = dict.clone();
+            let format = format.get_untracked();
+            spawn_local(async move {
+                let resp = async_get_dict_subtree(dict).await;
+                tip_or(
+                    resp,
+                    op_tiper.0,
+                    |dicts| {
+                        let content = match format {
+                            DictTransferFormat::Json => {
+                                serde_json::to_string_pretty(&dicts).unwrap_or_default()
+                            }
+                            DictTransferFormat::Csv => format!("\u{feff}{}", dicts_to_csv(&dicts)),
+                        };
+                        download
+                            .set(
+                                Some(
+                                    format!(
+                                        "data:{};charset=utf-8,{}",
+                                        format.mime(),
+                                        String::from(js_sys::encode_uri_component(&content)),
+                                    ),
+                                ),
+                            );
+                    },
+                );
+            });
+        }>
+            <fieldset class="p-4 w-full fieldset">
+                <label class="label">格式</label>
+                <DictTransferFormatSelect format on_change=move || download.set(None) />
+                <p class="label">"包含当前字典、全部下级字典及其字典项"</p>
+                <OpTip content=op_tiper.0 />
+                <button class="mt-4 btn btn-neutral" type="submit">
+                    导出
+                </button>
+                {move || {
+                    let file_name = file_name.clone();
+                    download
+                        .get()
+                        .map(|href| {
+                            view! {
+                                <a class="mt-2 btn btn-link" href=href download=file_name()>
+                                    {format!("下载 {}", file_name())}
+                                </a>
+                            }
+                        })
+                }}
+            </fieldset>
+        </form>
+    }
+}
+
+/// 导入到当前字典下：先预览对比结果，确认后逐条新增、修改；文件中缺少的不会删除
+#[component]
+pub(crate) fn ImportDictForm(
+    open: RwSignal<bool>,
+    dict_resource: WebResult<DictListItem>,
+) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    let state = expect_context::<DictState>();
+    let dict = state.target_dict.get_untracked().unwrap();
+    let format = RwSignal::new(DictTransferFormat::Json);
+    let content = RwSignal::new(String::new());
+    let plan = RwSignal::new(None::<DictImportPlan>);
+    let importing = RwSignal::new(false);
+    let op_tiper = OpTiper::new();
+    let op_tip = op_tiper.0;
+    let warn = move |content: String| {
+        op_tip.set(Some(OpTipData {
+            typ: OpTipType::Warning,
+            class: "".into(),
+            content,
+        }))
+    };
+    let preview = move |_| {
+        let dict = dict.clone();
+        let format = format.get_untracked();
+        let content = content.get_untracked();
+        plan.set(None);
+        spawn_local(async move {
+            let imported = match format {
+                DictTransferFormat::Json => serde_json::from_str::<Vec<DictTransferDict>>(&content)
+                    .map_err(|err| format!("JSON格式错误：{}", err)),
+                DictTransferFormat::Csv => dicts_from_csv(&content),
+            };
+            let mut imported = match imported {
+                Ok(imported) => imported,
+                Err(err) => return warn(err),
+            };
+            let (root, current) = match (
+                async_get_dicts().await,
+                async_get_dict_subtree(dict.clone()).await,
+            ) {
+                (Ok(root), Ok(current)) => (root, current),
+                (Err(err), _) | (_, Err(err)) => return warn(err.to_string()),
+            };
+            if let Some(outside) = imported.iter().find(|it| {
+                contains_dict(&root, &it.dict_code) && !contains_dict(&dict, &it.dict_code)
+            }) {
+                return warn(format!(
+                    "字典{}已存在且不在{}下",
+                    outside.dict_code, dict.dict_code
+                ));
+            }
+            if let Err(err) = check_import_parents(&current, &imported) {
+                return warn(err);
+            }
+            if format == DictTransferFormat::Csv {
+                keep_current_flags(&mut imported, &current);
+            }
+            op_tip.set(None);
+            plan.set(Some(DictImportPlan::diff(&current, &imported)));
+        });
+    };
+    view! {
+        <form on:submit=move |ev| {
+            ev.prevent_default();
+            let Some(plan) = plan.get_untracked() else {
+                return;
+            };
+            importing.set(true);
+            spawn_local(async move {
+                let resp = apply_import(plan).await;
+                importing.set(false);
+                tip_or(
+                    resp,
+                    op_tiper.0,
+                    |count| {
+                        dict_resource.refetch();
+                        open.set(false);
+                        app_state.success(&format!("导入成功，共变更{}条。", count));
+                    },
+                );
+            });
+        }>
+            <fieldset class="p-4 w-full fieldset">
+                <label class="label">格式</label>
+                <DictTransferFormatSelect format on_change=move || plan.set(None) />
+                <label class="mt-4 mb-2 label">文件内容</label>
+                <textarea
+                    class="w-full h-48 font-mono textarea"
+                    placeholder="粘贴导出的JSON或CSV内容"
+                    on:input:target=move |ev| {
+                        content.set(ev.target().value());
+                        plan.set(None);
+                    }
+                >
+                    {move || content.get_untracked()}
+                </textarea>
+                <button class="mt-2 btn" type="button" on:click=preview>
+                    预览
+                </button>
+                {move || plan.get().map(|plan| view! { <DictImportPreview plan /> })}
+                <OpTip content=op_tiper.0 />
+                <button
+                    class="mt-4 btn btn-neutral"
+                    type="submit"
+                    disabled=move || {
+                        importing.get() || plan.get().map_or(true, |plan| plan.is_empty())
+                    }
+                >
+                    确认导入
+                </button>
+            </fieldset>
+        </form>
+    }
+}
+
+#[component]
+fn DictTransferFormatSelect(
+    format: RwSignal<DictTransferFormat>,
+    on_change: impl Fn() + 'static,
+) -> impl IntoView {
+    view! {
+        <select
+            class="w-full select"
+            on:change:target=move |ev| {
+                format.set(ev.target().value().try_into().unwrap_or_default());
+                on_change();
+            }
+            prop:value=move || format.get().to_string()
+        >
+            {DictTransferFormat::all()
+                .into_iter()
+                .map(|format| {
+                    view! { <option value=format.to_string()>{format.label()}</option> }
+                })
+                .collect_view()}
+        </select>
+    }
+}
+
+#[component]
+fn DictImportPreview(plan: DictImportPlan) -> impl IntoView {
+    let badge = |kind: DictChangeKind| {
+        let class = match kind {
+            DictChangeKind::Added => "badge badge-success",
+            DictChangeKind::Changed => "badge badge-warning",
+            DictChangeKind::Missing => "badge badge-ghost",
+        };
+        view! { <span class=class>{kind.label()}</span> }
+    };
+    if plan.dicts.is_empty() && plan.items.is_empty() {
+        return view! { <p class="mt-4">"没有变更"</p> }.into_any();
+    }
+    view! {
+        <table class="table mt-4 table-xs">
+            <thead>
+                <tr>
+                    <th>变更</th>
+                    <th>字典</th>
+                    <th>字典项</th>
+                    <th>名称</th>
+                </tr>
+            </thead>
+            <tbody>
+                {plan
+                    .dicts
+                    .into_iter()
+                    .map(|(kind, dict)| {
+                        view! {
+                            <tr>
+                                <td>{badge(kind)}</td>
+                                <td>{dict.dict_code}</td>
+                                <td></td>
+                                <td>{dict.dict_name}</td>
+                            </tr>
+                        }
+                    })
+                    .collect_view()}
+                {plan
+                    .items
+                    .into_iter()
+                    .map(|(kind, dict_code, item)| {
+                        view! {
+                            <tr>
+                                <td>{badge(kind)}</td>
+                                <td>{dict_code}</td>
+                                <td>{item.item_value}</td>
+                                <td>{item.item_name}</td>
+                            </tr>
+                        }
+                    })
+                    .collect_view()}
+            </tbody>
+        </table>
+    }
+    .into_any()
+}
+
+/// 先按顺序处理字典（上级在前），再处理字典项，遇到错误即停止
+async fn apply_import(plan: DictImportPlan) -> Result<usize, ApiError> {
+    let mut count = 0;
+    for (kind, dict) in plan.dicts {
+        match kind {
+            DictChangeKind::Added => {
+                async_add_dict(AddDictReqExt {
+                    base: AddDictReq {
+                        dict_code: dict.dict_code,
+                        dict_name: dict.dict_name,
+                        parent_code: dict.parent_code,
+                        public: dict.public,
+                        remark: dict.remark,
+                        status: dict.status,
+                    },
+                    labels: dict.labels.unwrap_or_default(),
+                    ext_schema: vec![],
+                })
+                .await?
+            }
+            DictChangeKind::Changed => {
+                async_update_dict(UpdateDictReqExt {
+                    base: UpdateDictReq {
+                        dict_code: dict.dict_code,
+                        dict_name: dict.dict_name,
+                        parent_code: dict.parent_code,
+                        public: dict.public,
+                        remark: dict.remark,
+                        status: dict.status,
+                    },
+                    labels: dict.labels,
+                    ext_schema: None,
+                })
+                .await?
+            }
+            DictChangeKind::Missing => continue,
+        }
+        count += 1;
+    }
+    // 上级也在本次新增的字典项排在后面，这样每个字典通常只需查询一次上级id
+    let added: HashSet<(String, String)> = plan
+        .items
+        .iter()
+        .filter(|(kind, _, _)| *kind == DictChangeKind::Added)
+        .map(|(_, dict_code, item)| (dict_code.clone(), item.item_value.clone()))
+        .collect();
+    let mut items = plan.items;
+    items.sort_by_key(|(kind, dict_code, item)| {
+        *kind == DictChangeKind::Added
+            && item.parent_value.as_ref().is_some_and(|parent_value| {
+                added.contains(&(dict_code.clone(), parent_value.clone()))
+            })
+    });
+    // 字典代码 -> (字典项值 -> id)；上级字典项可能刚刚导入，查不到且字典有新增时才重新查询
+    let mut parent_ids: HashMap<String, HashMap<String, i32>> = HashMap::new();
+    let mut dirty_dicts = HashSet::new();
+    for (kind, dict_code, item) in items {
+        match (kind, item.id) {
+            (DictChangeKind::Added, _) => {
+                let parent_id = match item.parent_value.as_ref() {
+                    Some(parent_value) => {
+                        let cached = parent_ids
+                            .get(&dict_code)
+                            .and_then(|ids| ids.get(parent_value))
+                            .copied();
+                        if cached.is_none()
+                            && (!parent_ids.contains_key(&dict_code)
+                                || dirty_dicts.contains(&dict_code))
+                        {
+                            let ids = async_get_dict_items(Some(dict_code.clone()))
+                                .await?
+                                .into_iter()
+                                .map(|it| (it.item_value.clone(), it.id))
+                                .collect::<HashMap<_, _>>();
+                            parent_ids.insert(dict_code.clone(), ids);
+                            dirty_dicts.remove(&dict_code);
+                        }
+                        parent_ids
+                            .get(&dict_code)
+                            .and_then(|ids| ids.get(parent_value))
+                            .copied()
+                    }
+                    None => None,
+                };
+                dirty_dicts.insert(dict_code.clone());
+                async_add_dict_item(AddDictItemReqExt {
+                    base: AddDictItemReq {
+                        dict_code,
+                        item_name: item.item_name,
+                        item_value: item.item_value,
+                        public: item.public,
+                        remark: item.remark,
+                        status: item.status,
+                    },
+                    labels: item.labels.unwrap_or_default(),
+                    parent_id,
+                    color: item.color,
+                    icon: item.icon,
+                    ext: item.ext,
+                    valid_from: item.valid_from,
+                    valid_until: item.valid_until,
+                })
+                .await?
+            }
+            (DictChangeKind::Changed, Some(id)) => {
+                async_update_dict_item(UpdateDictItemReqExt {
+                    base: UpdateDictItemReq {
+                        id,
+                        item_name: item.item_name,
+                        item_value: item.item_value,
+                        public: item.public,
+                        remark: item.remark,
+                        status: item.status,
+                    },
+                    labels: item.labels,
+                    color: item.color,
+                    icon: item.icon,
+                    ext: item.ext,
+                    valid_from: item.valid_from,
+                    valid_until: item.valid_until,
+                })
+                .await?
+            }
+            _ => continue,
+        }
+        count += 1;
+    }
+    Ok(count)
+}
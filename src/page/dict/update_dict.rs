@@ -2,11 +2,17 @@ use leptos::{prelude::*, task::spawn_local};
 use tower::{
     management_model::{DictListItem, UpdateDictReq},
     tauri_web::prelude::*,
+    web::common::WebResultExt,
+    web_model::{OpTipData, OpTipType},
 };
 
 use crate::{
-    page::{component::StatusSelect, DictState},
-    service::async_update_dict,
+    model::{LocaleLabels, UpdateDictReqExt},
+    page::{
+        component::{LocaleTabs, StatusSelect},
+        flatten_dicts, DictExtFieldRow, DictExtSchemaEditor, DictLoadStatus, DictState,
+    },
+    service::{async_get_dict_list, async_update_dict},
 };
 
 #[component]
@@ -17,11 +23,49 @@ pub(crate) fn UpdateDictForm(
     let app_state = expect_context::<AppState>();
     let state = expect_context::<DictState>();
     let form = UpdateDictFormData::new(state.target_dict);
+    let dict_code = form.dict_code;
+    let parent_code = form.parent_code;
+    let labels = form.labels;
+    let ext_schema = form.ext_schema;
+    // 多语言名称、扩展字段定义加载成功前不能提交，避免用空值覆盖
+    let loaded = RwSignal::new(None::<Result<(), String>>);
+    spawn_local(async move {
+        let dict_code = dict_code.get_untracked();
+        let result = async_get_dict_list().await.map(|dicts| {
+            if let Some(dict) = dicts
+                .into_iter()
+                .find(|dict| dict.base.dict_code == dict_code)
+            {
+                labels.set(dict.labels);
+                ext_schema.set(
+                    dict.ext_schema
+                        .into_iter()
+                        .map(|field| DictExtFieldRow::new(Some(field)))
+                        .collect(),
+                );
+            }
+        });
+        loaded.set(Some(result.map_err(|err| err.to_string())));
+    });
     let op_tiper = OpTiper::new();
     view! {
         <form on:submit=move |ev| {
             ev.prevent_default();
-            let req = form.to_req();
+            let req = match form.to_req() {
+                Ok(req) => req,
+                Err(content) => {
+                    op_tiper
+                        .0
+                        .set(
+                            Some(OpTipData {
+                                typ: OpTipType::Warning,
+                                class: "".into(),
+                                content,
+                            }),
+                        );
+                    return;
+                }
+            };
             spawn_local(async move {
                 let resp = async_update_dict(req).await;
                 tip_or(
@@ -48,30 +92,55 @@ pub(crate) fn UpdateDictForm(
                 />
                 <p class="validator-hint">必须输入</p>
                 <label class="label">名称</label>
-                <input
-                    type="text"
-                    class="w-full input validator"
-                    minlength="1"
-                    maxlength="50"
-                    required
-                    bind:value=form.dict_name
-                />
-                <p class="validator-hint">必须输入</p>
-                <label class="label">上级代码</label>
-                <input
-                    type="text"
-                    class="w-full input validator"
-                    minlength="1"
-                    maxlength="50"
-                    bind:value=form.parent_code
-                />
+                <LocaleTabs name=form.dict_name labels=form.labels />
                 <p class="validator-hint">必须输入</p>
+                <label class="label">上级字典</label>
+                <Suspense fallback=move || {
+                    view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+                }>
+                    {move || {
+                        dict_resource
+                            .to_view(move |root| {
+                                let dicts = flatten_dicts(&root, &dict_code.get_untracked());
+                                view! {
+                                    <select
+                                        class="w-full select"
+                                        on:change:target=move |ev| {
+                                            parent_code.set(ev.target().value());
+                                        }
+                                        prop:value=move || parent_code.get()
+                                    >
+                                        <option value="">"（顶级）"</option>
+                                        {dicts
+                                            .into_iter()
+                                            .map(|(depth, dict)| {
+                                                view! {
+                                                    <option value=dict.dict_code.clone()>
+                                                        {format!(
+                                                            "{}{}（{}）",
+                                                            "　".repeat(depth),
+                                                            dict.dict_name,
+                                                            dict.dict_code,
+                                                        )}
+                                                    </option>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </select>
+                                }
+                                    .into_any()
+                            })
+                    }}
+                </Suspense>
+                <p class="label">不能选择自身及其下级字典</p>
                 <label class="label">是否公共</label>
                 <StatusSelect selected=form.public placeholder="是否公共" />
                 <p class="validator-hint">必须输入</p>
                 <label class="label">是否有效</label>
                 <StatusSelect selected=form.status placeholder="是否有效" />
                 <p class="validator-hint">必须输入</p>
+                <DictExtSchemaEditor rows=form.ext_schema />
+                <p class="label">修改定义不会校验已有字典项</p>
                 <label class="mt-4 mb-2 label">备注</label>
                 <textarea
                     class="w-full textarea validator"
@@ -80,8 +149,13 @@ pub(crate) fn UpdateDictForm(
                 >
                     {move || form.remark.get()}
                 </textarea>
+                <DictLoadStatus loaded what="多语言名称、扩展字段" />
                 <OpTip content=op_tiper.0 />
-                <button class="mt-4 btn btn-neutral" type="submit">
+                <button
+                    class="mt-4 btn btn-neutral"
+                    type="submit"
+                    disabled=move || !matches!(loaded.get(), Some(Ok(_)))
+                >
                     确定
                 </button>
             </fieldset>
@@ -96,6 +170,8 @@ struct UpdateDictFormData {
     public: RwSignal<YesNo>,
     remark: RwSignal<String>,
     status: RwSignal<YesNo>,
+    labels: RwSignal<LocaleLabels>,
+    ext_schema: RwSignal<Vec<DictExtFieldRow>>,
 }
 impl UpdateDictFormData {
     fn new(dict: RwSignal<Option<DictListItem>>) -> Self {
@@ -107,16 +183,24 @@ impl UpdateDictFormData {
             public: RwSignal::new(dict.public),
             remark: RwSignal::new(dict.remark.unwrap_or_default()),
             status: RwSignal::new(dict.status),
+            labels: RwSignal::new(LocaleLabels::new()),
+            ext_schema: RwSignal::new(vec![]),
         }
     }
-    fn to_req(&self) -> UpdateDictReq {
-        UpdateDictReq {
-            dict_code: self.dict_code.get_untracked(),
-            dict_name: self.dict_name.get_untracked(),
-            parent_code: str_trim_to_option(self.parent_code.get_untracked()),
-            public: self.public.get_untracked(),
-            remark: str_trim_to_option(self.remark.get_untracked()),
-            status: self.status.get_untracked(),
-        }
+    fn to_req(&self) -> Result<UpdateDictReqExt, String> {
+        Ok(UpdateDictReqExt {
+            base: UpdateDictReq {
+                dict_code: self.dict_code.get_untracked(),
+                dict_name: self.dict_name.get_untracked(),
+                parent_code: str_trim_to_option(self.parent_code.get_untracked()),
+                public: self.public.get_untracked(),
+                remark: str_trim_to_option(self.remark.get_untracked()),
+                status: self.status.get_untracked(),
+            },
+            labels: Some(self.labels.get_untracked()),
+            ext_schema: Some(DictExtFieldRow::to_schema(
+                &self.ext_schema.get_untracked(),
+            )?),
+        })
     }
 }
@@ -1,24 +1,89 @@
 use leptos::{prelude::*, task::spawn_local};
 use tower::{
-    management_model::{DictItemListItem, UpdateDictItemReq},
+    management_model::UpdateDictItemReq,
     tauri_web::prelude::*,
+    web_model::{OpTipData, OpTipType},
 };
 
-use crate::{page::component::StatusSelect, service::async_update_dict_item};
+use crate::{
+    model::{
+        check_validity_window, parse_dict_ext, DictExtField, DictItemListItemExt, DictUsage,
+        DictUsageReq, LocaleLabels, UpdateDictItemReqExt,
+    },
+    page::{
+        component::{LocaleTabs, StatusSelect},
+        datetime_local_to_secs, secs_to_datetime_local, DictItemExtInput, DictItemStyleFields,
+        DictItemValidityFields, DictLoadStatus, DictUsageWarning,
+    },
+    service::{async_get_dict_ext_schema, async_get_dict_usage, async_update_dict_item},
+};
 
 #[component]
 pub(crate) fn UpdateItemForm(
     open: RwSignal<bool>,
-    item_resource: WebResult<Vec<DictItemListItem>>,
-    item: RwSignal<Option<DictItemListItem>>,
+    item_resource: WebResult<Vec<DictItemListItemExt>>,
+    item: RwSignal<Option<DictItemListItemExt>>,
 ) -> impl IntoView {
     let app_state = expect_context::<AppState>();
     let form = UpdateItemFormData::new(item);
+    let status = form.status;
+    let item_value = form.item_value;
+    // 停用或修改仍被引用的字典项的值前提示，引用未查询成功时不能提交
+    let was_enabled = status.get_untracked() == YesNo::Yes;
+    let old_value = item_value.get_untracked();
+    let usage = RwSignal::new(None::<Result<DictUsage, String>>);
+    let confirmed = RwSignal::new(false);
+    let usage_req = item.with_untracked(|item| {
+        let item = item.as_ref().unwrap();
+        DictUsageReq {
+            dict_code: item.dict_code.clone(),
+            item_value: Some(item.item_value.clone()),
+        }
+    });
+    let ext_schema = form.ext_schema;
+    let dict_code = form.dict_code.get_untracked();
+    let schema_loaded = RwSignal::new(None::<Result<(), String>>);
+    spawn_local(async move {
+        let item_usage = async_get_dict_usage(usage_req)
+            .await
+            .map_err(|err| err.to_string());
+        usage.set(Some(item_usage));
+        let result = async_get_dict_ext_schema(dict_code)
+            .await
+            .map(|schema| ext_schema.set(schema));
+        schema_loaded.set(Some(result.map_err(|err| err.to_string())));
+    });
+    let risky = move || {
+        (was_enabled && status.get() == YesNo::No)
+            || item_value.with(|value| value.trim() != old_value.trim())
+    };
+    let blocked = move || {
+        !matches!(schema_loaded.get(), Some(Ok(_)))
+            || risky()
+                && usage.with(|usage| match usage {
+                    Some(Ok(usage)) => !usage.is_empty() && !confirmed.get(),
+                    _ => true,
+                })
+    };
     let op_tiper = OpTiper::new();
     view! {
         <form on:submit=move |ev| {
             ev.prevent_default();
-            let req = form.to_req();
+            let req = match form.to_req() {
+                Ok(req) => req,
+                Err(content) => {
+                    op_tiper
+                        .0
+                        .set(
+                            Some(OpTipData {
+                                typ: OpTipType::Warning,
+                                class: "".into(),
+                                content,
+                            }),
+                        );
+                    return;
+                }
+            };
             spawn_local(async move {
                 let resp = async_update_dict_item(req).await;
                 tip_or(
@@ -45,14 +110,7 @@ pub(crate) fn UpdateItemForm(
                 />
                 <p class="validator-hint">必须输入</p>
                 <label class="label">名称</label>
-                <input
-                    type="text"
-                    class="w-full input validator"
-                    minlength="1"
-                    maxlength="50"
-                    required
-                    bind:value=form.item_name
-                />
+                <LocaleTabs name=form.item_name labels=form.labels />
                 <p class="validator-hint">必须输入</p>
                 <label class="label">值</label>
                 <input
@@ -70,6 +128,24 @@ pub(crate) fn UpdateItemForm(
                 <label class="label">是否有效</label>
                 <StatusSelect selected=form.status placeholder="是否有效" />
                 <p class="validator-hint">必须输入</p>
+                <DictItemStyleFields name=form.item_name color=form.color icon=form.icon />
+                <DictItemValidityFields valid_from=form.valid_from valid_until=form.valid_until />
+                <DictItemExtInput ext=form.ext schema=form.ext_schema />
+                {move || {
+                    risky()
+                        .then(|| match usage.get() {
+                            None => view! { <span class="mt-2 loading loading-dots"></span> }.into_any(),
+                            Some(Ok(usage)) => view! { <DictUsageWarning usage confirmed /> }.into_any(),
+                            Some(Err(err)) => {
+                                view! {
+                                    <div class="mt-2 alert alert-error">
+                                        {format!("查询引用失败：{}，无法确认是否仍被引用。", err)}
+                                    </div>
+                                }
+                                    .into_any()
+                            }
+                        })
+                }}
                 <label class="mt-4 mb-2 label">备注</label>
                 <textarea
                     class="w-full textarea validator"
@@ -78,8 +154,13 @@ pub(crate) fn UpdateItemForm(
                 >
                     {move || form.remark.get()}
                 </textarea>
+                <DictLoadStatus loaded=schema_loaded what="扩展字段定义" />
                 <OpTip content=op_tiper.0 />
-                <button class="mt-4 btn btn-neutral" type="submit">
+                <button
+                    class="mt-4 btn btn-neutral"
+                    type="submit"
+                    disabled=blocked
+                >
                     确定
                 </button>
             </fieldset>
@@ -95,10 +176,26 @@ struct UpdateItemFormData {
     public: RwSignal<YesNo>,
     remark: RwSignal<String>,
     status: RwSignal<YesNo>,
+    labels: RwSignal<LocaleLabels>,
+    color: RwSignal<Option<String>>,
+    icon: RwSignal<Option<String>>,
+    ext: RwSignal<String>,
+    ext_schema: RwSignal<Vec<DictExtField>>,
+    valid_from: RwSignal<String>,
+    valid_until: RwSignal<String>,
 }
 impl UpdateItemFormData {
-    fn new(item: RwSignal<Option<DictItemListItem>>) -> Self {
-        let item = item.get_untracked().unwrap();
+    fn new(item: RwSignal<Option<DictItemListItemExt>>) -> Self {
+        let DictItemListItemExt {
+            base: item,
+            labels,
+            color,
+            icon,
+            ext,
+            valid_from,
+            valid_until,
+            ..
+        } = item.get_untracked().unwrap();
         Self {
             id: Signal::from(item.id),
             dict_code: Signal::from(item.dict_code),
@@ -107,16 +204,40 @@ impl UpdateItemFormData {
             public: RwSignal::new(item.public),
             remark: RwSignal::new(item.remark.unwrap_or_default()),
             status: RwSignal::new(item.status),
+            labels: RwSignal::new(labels),
+            color: RwSignal::new(color),
+            icon: RwSignal::new(icon),
+            ext: RwSignal::new(
+                ext.map(|ext| serde_json::to_string_pretty(&ext).unwrap_or_default())
+                    .unwrap_or_default(),
+            ),
+            ext_schema: RwSignal::new(vec![]),
+            valid_from: RwSignal::new(valid_from.map(secs_to_datetime_local).unwrap_or_default()),
+            valid_until: RwSignal::new(valid_until.map(secs_to_datetime_local).unwrap_or_default()),
         }
     }
-    fn to_req(&self) -> UpdateDictItemReq {
-        UpdateDictItemReq {
-            id: self.id.get_untracked(),
-            item_name: self.item_name.get_untracked(),
-            item_value: self.item_value.get_untracked(),
-            public: self.public.get_untracked(),
-            remark: str_trim_to_option(self.remark.get_untracked()),
-            status: self.status.get_untracked(),
-        }
+    fn to_req(&self) -> Result<UpdateDictItemReqExt, String> {
+        let ext = self
+            .ext_schema
+            .with_untracked(|schema| parse_dict_ext(schema, &self.ext.get_untracked()))?;
+        let valid_from = datetime_local_to_secs(&self.valid_from.get_untracked());
+        let valid_until = datetime_local_to_secs(&self.valid_until.get_untracked());
+        check_validity_window(valid_from, valid_until)?;
+        Ok(UpdateDictItemReqExt {
+            base: UpdateDictItemReq {
+                id: self.id.get_untracked(),
+                item_name: self.item_name.get_untracked(),
+                item_value: self.item_value.get_untracked(),
+                public: self.public.get_untracked(),
+                remark: str_trim_to_option(self.remark.get_untracked()),
+                status: self.status.get_untracked(),
+            },
+            labels: Some(self.labels.get_untracked()),
+            color: self.color.get_untracked(),
+            icon: self.icon.get_untracked(),
+            ext,
+            valid_from,
+            valid_until,
+        })
     }
 }
@@ -0,0 +1,101 @@
+use leptos::{prelude::*, task::spawn_local};
+use tower::tauri_web::prelude::*;
+
+use crate::{
+    model::{EvaluateFlagReq, EvaluateFlagResp, FlagListItem},
+    service::async_evaluate_flag,
+};
+
+#[component]
+pub(crate) fn EvaluateFlagForm(flags_resource: WebResult<Vec<FlagListItem>>) -> impl IntoView {
+    let flag_id = RwSignal::new(None::<i32>);
+    let user_id = RwSignal::new("".to_string());
+    let result = RwSignal::new(None::<EvaluateFlagResp>);
+    let op_tiper = OpTiper::new();
+    view! {
+        <form on:submit=move |ev| {
+            ev.prevent_default();
+            let Some(flag_id) = flag_id.get_untracked() else {
+                return;
+            };
+            let req = EvaluateFlagReq {
+                flag_id,
+                user_id: user_id.get_untracked().trim().to_string(),
+            };
+            spawn_local(async move {
+                let resp = async_evaluate_flag(req).await;
+                tip_or(
+                    resp,
+                    op_tiper.0,
+                    |resp| {
+                        result.set(Some(resp));
+                    },
+                );
+            });
+        }>
+            <fieldset class="p-4 w-full fieldset">
+                <label class="label">开关</label>
+                <Suspense fallback=move || {
+                    view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+                }>
+                    {move || {
+                        flags_resource
+                            .to_view(move |list| {
+                                view! {
+                                    <select
+                                        class="w-full select validator"
+                                        required
+                                        on:change:target=move |ev| {
+                                            flag_id.set(ev.target().value().parse().ok());
+                                            result.set(None);
+                                        }
+                                    >
+                                        <option disabled selected value="">
+                                            选择开关
+                                        </option>
+                                        {list
+                                            .into_iter()
+                                            .map(|flag| {
+                                                view! {
+                                                    <option value=flag.id>
+                                                        {format!("{}（{}）", flag.flag_name, flag.flag_key)}
+                                                    </option>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </select>
+                                }
+                                    .into_any()
+                            })
+                    }}
+                </Suspense>
+                <p class="validator-hint">必须输入</p>
+                <label class="label">用户ID</label>
+                <input
+                    type="text"
+                    class="w-full input validator"
+                    minlength="1"
+                    required
+                    bind:value=user_id
+                />
+                <p class="validator-hint">必须输入</p>
+                <OpTip content=op_tiper.0 />
+                {move || {
+                    result
+                        .get()
+                        .map(|result| {
+                            view! {
+                                <div class="alert alert-info">
+                                    <span class="font-bold">{result.variant}</span>
+                                    <span>{result.reason}</span>
+                                </div>
+                            }
+                        })
+                }}
+                <button class="mt-4 btn btn-neutral" type="submit">
+                    评估
+                </button>
+            </fieldset>
+        </form>
+    }
+}
@@ -0,0 +1,216 @@
+use std::collections::HashSet;
+
+use leptos::{prelude::*, task::spawn_local};
+use tower::{tauri_web::prelude::*, web::component::Checkboxs};
+
+use crate::{
+    model::{FlagKind, FlagListItem, FlagListReq},
+    page::component::AppSelect,
+    service::{async_delete_flag, async_get_flags},
+};
+
+use super::*;
+
+#[component]
+pub fn FlagPane() -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    let query_form = FlagQueryFormData::new();
+    let flags_resource = LocalResource::new(move || async_get_flags(query_form.to_req()));
+    let current_flag = RwSignal::new(None::<FlagListItem>);
+    let open_save = RwSignal::new(false);
+    let open_evaluate = RwSignal::new(false);
+    view! {
+        <div class="flex gap-4">
+            <AppSelect selected=query_form.app />
+            <label class="floating-label">
+                <span>标识</span>
+                <input
+                    type="text"
+                    placeholder="开关标识"
+                    class="input input-md"
+                    bind:value=query_form.flag_key
+                />
+            </label>
+            <Checkboxs
+                name="flag_status"
+                values=YesNo::all()
+                checked=query_form.status
+                multi=false
+            />
+            <button
+                class="btn"
+                on:click=move |_| {
+                    flags_resource.refetch();
+                }
+            >
+                查询
+            </button>
+            <button
+                class="btn"
+                on:click=move |_| {
+                    query_form.reset();
+                }
+            >
+                重置
+            </button>
+            <button
+                class="btn"
+                on:click=move |_| {
+                    current_flag.set(None);
+                    open_save.set(true);
+                }
+            >
+                新增
+            </button>
+            <button
+                class="btn"
+                on:click=move |_| {
+                    open_evaluate.set(true);
+                }
+            >
+                评估预览
+            </button>
+        </div>
+        <table class="table">
+            <thead>
+                <tr>
+                    <th></th>
+                    <th>应用</th>
+                    <th>标识</th>
+                    <th>名称</th>
+                    <th>类型</th>
+                    <th>灰度</th>
+                    <th>定向</th>
+                    <th>默认值</th>
+                    <th>状态</th>
+                    <th>更新时间</th>
+                    <th>操作</th>
+                </tr>
+            </thead>
+            <tbody>
+                <Suspense fallback=move || {
+                    view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+                }>
+                    {move || {
+                        flags_resource
+                            .to_view(|list| {
+                                list.into_iter()
+                                    .enumerate()
+                                    .map(|(index, flag)| {
+                                        let flag = flag.clone();
+                                        let targets = format!(
+                                            "用户 {} / 用户组 {} / 等级 {}",
+                                            flag.target_user_ids.len(),
+                                            flag.target_group_ids.len(),
+                                            flag.target_vip_levels.len(),
+                                        );
+                                        let variants = if flag.kind == FlagKind::Multivariant {
+                                            flag.variants
+                                                .iter()
+                                                .map(|variant| {
+                                                    format!("{}:{}%", variant.value, variant.weight)
+                                                })
+                                                .collect::<Vec<_>>()
+                                                .join(" ")
+                                        } else {
+                                            "".to_string()
+                                        };
+                                        let flag_id = flag.id;
+                                        view! {
+                                            <tr>
+                                                <th>{index + 1}</th>
+                                                <td>{flag.app.label()}</td>
+                                                <td>{flag.flag_key.clone()}</td>
+                                                <td>{flag.flag_name.clone()}</td>
+                                                <td>{flag.kind.label()}</td>
+                                                <td>{format!("{}% {}", flag.rollout, variants)}</td>
+                                                <td>{targets}</td>
+                                                <td>{flag.default_variant.clone()}</td>
+                                                <td>{flag.status.label()}</td>
+                                                <td>
+                                                    {from_secs_and_default(flag.updated.unwrap_or(flag.created))}
+                                                </td>
+                                                <td class="flex gap-4">
+                                                    <button
+                                                        class="btn btn-xs"
+                                                        on:click=move |_| {
+                                                            current_flag.set(Some(flag.clone()));
+                                                            open_save.set(true)
+                                                        }
+                                                    >
+                                                        修改
+                                                    </button>
+                                                    <ConfirmDialogButton
+                                                        btn="删除"
+                                                        btn_class="btn-xs"
+                                                        title="删除开关"
+                                                        message="确认删除？"
+                                                        on_ok=move || {
+                                                            spawn_local(async move {
+                                                                match async_delete_flag(flag_id).await {
+                                                                    Ok(_) => {
+                                                                        flags_resource.refetch();
+                                                                        app_state.success("删除成功")
+                                                                    }
+                                                                    Err(err) => app_state.error(err.to_string()),
+                                                                }
+                                                            });
+                                                        }
+                                                    />
+                                                </td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view()
+                                    .into_any()
+                            })
+                    }}
+                </Suspense>
+            </tbody>
+        </table>
+        <Show when=move || { current_flag.get().is_none() && open_save.get() }>
+            <Dialog open=open_save title="新增开关">
+                <SaveFlagForm open=open_save flags_resource flag=current_flag />
+            </Dialog>
+        </Show>
+        <Show when=move || { current_flag.get().is_some() && open_save.get() }>
+            <Dialog open=open_save title="修改开关">
+                <SaveFlagForm open=open_save flags_resource flag=current_flag />
+            </Dialog>
+        </Show>
+        <Show when=move || { open_evaluate.get() }>
+            <Dialog open=open_evaluate title="评估预览">
+                <EvaluateFlagForm flags_resource />
+            </Dialog>
+        </Show>
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct FlagQueryFormData {
+    app: RwSignal<App>,
+    flag_key: RwSignal<String>,
+    status: RwSignal<HashSet<YesNo>>,
+}
+
+impl FlagQueryFormData {
+    fn new() -> Self {
+        Self {
+            app: RwSignal::new(App::TowerServer),
+            flag_key: RwSignal::new("".to_string()),
+            status: RwSignal::new(HashSet::new()),
+        }
+    }
+    fn reset(&self) {
+        self.app.set(App::TowerServer);
+        self.flag_key.set("".to_string());
+        self.status.set(HashSet::new());
+    }
+    fn to_req(&self) -> FlagListReq {
+        FlagListReq {
+            app_id: self.app.get_untracked().to_string(),
+            flag_key: self.flag_key.get_untracked(),
+            status: self.status.get_untracked().iter().next().copied(),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+mod index;
+pub use index::*;
+
+mod save_flag;
+pub use save_flag::*;
+
+mod evaluate_flag;
+pub use evaluate_flag::*;
@@ -0,0 +1,441 @@
+use std::collections::HashSet;
+
+use leptos::{prelude::*, task::spawn_local};
+use tower::{
+    common::dict::VipLevel,
+    tauri_web::prelude::*,
+    web::component::Checkboxs,
+    web_model::{OpTipData, OpTipType},
+};
+
+use crate::{
+    model::{FlagKind, FlagListItem, FlagVariant, SaveFlagReq},
+    page::component::{AppSelect, StatusSelect},
+    service::async_save_flag,
+};
+
+#[component]
+pub(crate) fn SaveFlagForm(
+    open: RwSignal<bool>,
+    flags_resource: WebResult<Vec<FlagListItem>>,
+    flag: RwSignal<Option<FlagListItem>>,
+) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    let form = SaveFlagFormData::new(flag.get_untracked());
+    let op_tiper = OpTiper::new();
+    view! {
+        <form on:submit=move |ev| {
+            ev.prevent_default();
+            let req = match form.to_req() {
+                Ok(req) => req,
+                Err(content) => {
+                    op_tiper
+                        .0
+                        .set(
+                            Some(OpTipData {
+                                typ: OpTipType::Warning,
+                                class: "".into(),
+                                content,
+                            }),
+                        );
+                    return;
+                }
+            };
+            spawn_local(async move {
+                let resp = async_save_flag(req).await;
+                tip_or(
+                    resp,
+                    op_tiper.0,
+                    |_| {
+                        flags_resource.refetch();
+                        open.set(false);
+                        app_state.success("保存开关成功。");
+                    },
+                );
+            });
+        }>
+            <fieldset class="p-4 w-full fieldset">
+                <label class="label">应用</label>
+                <AppSelect selected=form.app />
+                <p class="validator-hint">必须输入</p>
+                <label class="label">标识</label>
+                <input
+                    type="text"
+                    class="w-full input validator"
+                    minlength="1"
+                    maxlength="50"
+                    required
+                    disabled=form.id.is_some()
+                    bind:value=form.flag_key
+                />
+                <p class="validator-hint">必须输入</p>
+                <label class="label">名称</label>
+                <input
+                    type="text"
+                    class="w-full input validator"
+                    minlength="1"
+                    maxlength="50"
+                    required
+                    bind:value=form.flag_name
+                />
+                <p class="validator-hint">必须输入</p>
+                <label class="label">类型</label>
+                <select
+                    class="w-full select"
+                    on:change:target=move |ev| {
+                        form.kind.set(ev.target().value().try_into().unwrap_or_default());
+                    }
+                    prop:value=move || form.kind.get().to_string()
+                >
+                    {FlagKind::all()
+                        .into_iter()
+                        .map(|kind| {
+                            view! { <option value=kind.to_string()>{kind.label()}</option> }
+                        })
+                        .collect_view()}
+                </select>
+                <Show when=move || form.kind.get() == FlagKind::Multivariant>
+                    <label class="mt-4 mb-2 label">变体</label>
+                    <textarea
+                        class="w-full textarea"
+                        placeholder="每行一个变体，格式：值:权重，权重之和为100"
+                        on:input:target=move |ev| { form.variants.set(ev.target().value()) }
+                    >
+                        {move || form.variants.get_untracked()}
+                    </textarea>
+                </Show>
+                <label class="label">默认值</label>
+                <input
+                    type="text"
+                    class="w-full input validator"
+                    minlength="1"
+                    maxlength="50"
+                    required
+                    placeholder="未命中定向及灰度时的取值"
+                    bind:value=form.default_variant
+                />
+                <p class="validator-hint">必须输入</p>
+                <label class="label">{move || format!("灰度比例 {}%", form.rollout.get())}</label>
+                <input
+                    type="range"
+                    class="w-full range range-xs"
+                    min="0"
+                    max="100"
+                    prop:value=move || form.rollout.get().to_string()
+                    on:input:target=move |ev| {
+                        form.rollout.set(ev.target().value().parse().unwrap_or_default());
+                    }
+                />
+                <label class="mt-4 mb-2 label">定向用户</label>
+                <textarea
+                    class="w-full textarea"
+                    placeholder="用户ID，逗号或换行分隔"
+                    on:input:target=move |ev| { form.target_user_ids.set(ev.target().value()) }
+                >
+                    {move || form.target_user_ids.get_untracked()}
+                </textarea>
+                <label class="label">定向用户组</label>
+                <input
+                    type="text"
+                    class="w-full input"
+                    placeholder="用户组编码，逗号分隔"
+                    bind:value=form.target_group_ids
+                />
+                <label class="label">定向会员等级</label>
+                <Checkboxs
+                    name="flag_vip_level"
+                    values=VipLevel::all()
+                    checked=form.target_vip_levels
+                    multi=true
+                />
+                <label class="label">定向取值</label>
+                <input
+                    type="text"
+                    class="w-full input"
+                    maxlength="50"
+                    placeholder="命中定向时的取值，开关类型默认为true"
+                    bind:value=form.target_variant
+                />
+                <label class="label">是否有效</label>
+                <StatusSelect selected=form.status placeholder="是否有效" />
+                <p class="validator-hint">必须输入</p>
+                <label class="mt-4 mb-2 label">备注</label>
+                <textarea
+                    class="w-full textarea validator"
+                    maxlength="500"
+                    on:input:target=move |ev| { form.remark.set(ev.target().value()) }
+                >
+                    {move || form.remark.get_untracked()}
+                </textarea>
+                <OpTip content=op_tiper.0 />
+                <button class="mt-4 btn btn-neutral" type="submit">
+                    确定
+                </button>
+            </fieldset>
+        </form>
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SaveFlagFormData {
+    id: Option<i32>,
+    app: RwSignal<App>,
+    flag_key: RwSignal<String>,
+    flag_name: RwSignal<String>,
+    kind: RwSignal<FlagKind>,
+    variants: RwSignal<String>,
+    default_variant: RwSignal<String>,
+    rollout: RwSignal<u32>,
+    target_user_ids: RwSignal<String>,
+    target_group_ids: RwSignal<String>,
+    target_vip_levels: RwSignal<HashSet<VipLevel>>,
+    target_variant: RwSignal<String>,
+    remark: RwSignal<String>,
+    status: RwSignal<YesNo>,
+}
+impl SaveFlagFormData {
+    fn new(flag: Option<FlagListItem>) -> Self {
+        if flag.is_none() {
+            return Self {
+                id: None,
+                app: RwSignal::new(App::TowerServer),
+                flag_key: RwSignal::new("".to_string()),
+                flag_name: RwSignal::new("".to_string()),
+                kind: RwSignal::new(FlagKind::Boolean),
+                variants: RwSignal::new("".to_string()),
+                default_variant: RwSignal::new("false".to_string()),
+                rollout: RwSignal::new(0),
+                target_user_ids: RwSignal::new("".to_string()),
+                target_group_ids: RwSignal::new("".to_string()),
+                target_vip_levels: RwSignal::new(HashSet::new()),
+                target_variant: RwSignal::new("".to_string()),
+                remark: RwSignal::new("".to_string()),
+                status: RwSignal::new(YesNo::Yes),
+            };
+        }
+        let flag = flag.unwrap();
+        Self {
+            id: Some(flag.id),
+            app: RwSignal::new(flag.app),
+            flag_key: RwSignal::new(flag.flag_key),
+            flag_name: RwSignal::new(flag.flag_name),
+            kind: RwSignal::new(flag.kind),
+            variants: RwSignal::new(
+                flag.variants
+                    .iter()
+                    .map(|variant| format!("{}:{}", variant.value, variant.weight))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+            default_variant: RwSignal::new(flag.default_variant),
+            rollout: RwSignal::new(flag.rollout),
+            target_user_ids: RwSignal::new(flag.target_user_ids.join("\n")),
+            target_group_ids: RwSignal::new(flag.target_group_ids.join(",")),
+            target_vip_levels: RwSignal::new(
+                flag.target_vip_levels
+                    .into_iter()
+                    .filter_map(|level| VipLevel::try_from(level).ok())
+                    .collect(),
+            ),
+            target_variant: RwSignal::new(flag.target_variant.unwrap_or_default()),
+            remark: RwSignal::new(flag.remark.unwrap_or_default()),
+            status: RwSignal::new(flag.status),
+        }
+    }
+    fn to_req(&self) -> Result<SaveFlagReq, String> {
+        let kind = self.kind.get_untracked();
+        let variants = match kind {
+            FlagKind::Boolean => vec![],
+            FlagKind::Multivariant => parse_variants(&self.variants.get_untracked())?,
+        };
+        let default_variant = self.default_variant.get_untracked().trim().to_string();
+        let target_variant = str_trim_to_option(self.target_variant.get_untracked());
+        let rollout = self.rollout.get_untracked();
+        check_flag_values(
+            kind,
+            &variants,
+            &default_variant,
+            target_variant.as_ref(),
+            rollout,
+        )?;
+        Ok(SaveFlagReq {
+            id: self.id,
+            app_id: self.app.get_untracked().to_string(),
+            flag_key: self.flag_key.get_untracked().trim().to_string(),
+            flag_name: self.flag_name.get_untracked().trim().to_string(),
+            kind,
+            variants,
+            default_variant,
+            rollout,
+            target_user_ids: split_values(&self.target_user_ids.get_untracked()),
+            target_group_ids: split_values(&self.target_group_ids.get_untracked()),
+            target_vip_levels: self
+                .target_vip_levels
+                .get_untracked()
+                .iter()
+                .map(|level| level.to_string())
+                .collect(),
+            target_variant,
+            remark: str_trim_to_option(self.remark.get_untracked()),
+            status: self.status.get_untracked(),
+        })
+    }
+}
+
+/// 开关只能取true、false，多变体只能取已配置的变体；灰度比例不超过100
+fn check_flag_values(
+    kind: FlagKind,
+    variants: &[FlagVariant],
+    default_variant: &str,
+    target_variant: Option<&String>,
+    rollout: u32,
+) -> Result<(), String> {
+    let allowed: Vec<String> = match kind {
+        FlagKind::Boolean => vec!["true".to_string(), "false".to_string()],
+        FlagKind::Multivariant => variants
+            .iter()
+            .map(|variant| variant.value.clone())
+            .collect(),
+    };
+    if !allowed.iter().any(|value| value == default_variant) {
+        return Err(format!(
+            "默认值{}不在可选值{}中",
+            default_variant,
+            allowed.join("、")
+        ));
+    }
+    if let Some(target_variant) = target_variant {
+        if !allowed.contains(target_variant) {
+            return Err(format!(
+                "定向值{}不在可选值{}中",
+                target_variant,
+                allowed.join("、")
+            ));
+        }
+    }
+    if rollout > 100 {
+        return Err(format!("灰度比例{}%超过100%", rollout));
+    }
+    Ok(())
+}
+
+fn split_values(values: &str) -> Vec<String> {
+    values
+        .split(|c| c == ',' || c == '，' || c == '\n')
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+        .map(|value| value.to_string())
+        .collect()
+}
+
+fn parse_variants(variants: &str) -> Result<Vec<FlagVariant>, String> {
+    let variants = variants
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (value, weight) = line
+                .rsplit_once(':')
+                .ok_or_else(|| format!("变体格式错误：{}", line))?;
+            let weight = weight
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| format!("变体权重错误：{}", line))?;
+            Ok(FlagVariant {
+                value: value.trim().to_string(),
+                weight,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    if variants.len() < 2 {
+        return Err("多变体开关至少需要两个变体".to_string());
+    }
+    let total: u32 = variants.iter().map(|variant| variant.weight).sum();
+    if total != 100 {
+        return Err(format!("变体权重之和为{}，应为100", total));
+    }
+    Ok(variants)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variant(value: &str, weight: u32) -> FlagVariant {
+        FlagVariant {
+            value: value.to_string(),
+            weight,
+        }
+    }
+
+    #[test]
+    fn split_values_accepts_mixed_separators() {
+        assert_eq!(
+            split_values(" u1, u2，u3\n\n u4 "),
+            vec!["u1", "u2", "u3", "u4"]
+        );
+        assert!(split_values(" ,\n").is_empty());
+    }
+
+    #[test]
+    fn parse_variants_splits_on_last_colon() {
+        let variants = parse_variants("a:b: 30\n\n c:70 ").unwrap();
+        assert_eq!(variants, vec![variant("a:b", 30), variant("c", 70)]);
+    }
+
+    #[test]
+    fn parse_variants_rejects_bad_input() {
+        assert!(parse_variants("a:100").is_err());
+        assert!(parse_variants("a:50\nb").is_err());
+        assert!(parse_variants("a:50\nb:x").is_err());
+        assert_eq!(
+            parse_variants("a:50\nb:40"),
+            Err("变体权重之和为90，应为100".to_string())
+        );
+    }
+
+    #[test]
+    fn boolean_flag_only_allows_true_false() {
+        assert!(check_flag_values(FlagKind::Boolean, &[], "false", None, 0).is_ok());
+        assert!(check_flag_values(
+            FlagKind::Boolean,
+            &[],
+            "true",
+            Some(&"false".to_string()),
+            100
+        )
+        .is_ok());
+        assert!(check_flag_values(FlagKind::Boolean, &[], "on", None, 0).is_err());
+        assert!(
+            check_flag_values(FlagKind::Boolean, &[], "false", Some(&"yes".to_string()), 0)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn multivariant_flag_only_allows_configured_variants() {
+        let variants = vec![variant("a", 50), variant("b", 50)];
+        assert!(check_flag_values(
+            FlagKind::Multivariant,
+            &variants,
+            "a",
+            Some(&"b".to_string()),
+            20
+        )
+        .is_ok());
+        assert!(check_flag_values(FlagKind::Multivariant, &variants, "false", None, 0).is_err());
+        assert!(check_flag_values(
+            FlagKind::Multivariant,
+            &variants,
+            "a",
+            Some(&"c".to_string()),
+            0
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn rollout_is_capped_at_100() {
+        assert!(check_flag_values(FlagKind::Boolean, &[], "false", None, 101).is_err());
+    }
+}
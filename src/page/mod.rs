@@ -3,6 +3,12 @@ pub mod component;
 mod util;
 pub use util::*;
 
+mod approval;
+pub use approval::*;
+
+mod audit;
+pub use audit::*;
+
 mod config;
 pub use config::*;
 
@@ -12,5 +18,11 @@ pub use dashboard::*;
 mod dict;
 pub use dict::*;
 
+mod flag;
+pub use flag::*;
+
+mod template;
+pub use template::*;
+
 mod user;
 pub use user::*;
@@ -0,0 +1,92 @@
+use leptos::{prelude::*, task::spawn_local};
+use tower::tauri_web::prelude::*;
+
+use crate::{
+    model::{
+        ApplyConfigTemplateReq, ApplyConfigTemplateResp, ConfigTemplateListItem,
+        APP_VERSION_PATTERN,
+    },
+    page::component::AppSelect,
+    service::async_apply_config_template,
+};
+
+#[component]
+pub(crate) fn ApplyTemplateForm(
+    template: RwSignal<Option<ConfigTemplateListItem>>,
+) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    let template = template.get_untracked().unwrap();
+    let template_id = template.id;
+    let app = RwSignal::new(App::TowerServer);
+    let app_version = RwSignal::new("".to_string());
+    let summary = RwSignal::new(None::<ApplyConfigTemplateResp>);
+    let op_tiper = OpTiper::new();
+    view! {
+        <form on:submit=move |ev| {
+            ev.prevent_default();
+            let req = ApplyConfigTemplateReq {
+                template_id,
+                app_id: app.get_untracked().to_string(),
+                app_version: str_trim_to_option(app_version.get_untracked()),
+            };
+            spawn_local(async move {
+                let resp = async_apply_config_template(req).await;
+                tip_or(
+                    resp,
+                    op_tiper.0,
+                    |resp| {
+                        app_state.success("应用模板成功。");
+                        summary.set(Some(resp));
+                    },
+                );
+            });
+        }>
+            <fieldset class="p-4 w-full fieldset">
+                <label class="label">模板</label>
+                <input
+                    type="text"
+                    class="w-full input"
+                    disabled
+                    prop:value=format!("{}（{} 项）", template.template_name, template.items.len())
+                />
+                <label class="label">应用</label>
+                <AppSelect selected=app />
+                <p class="validator-hint">必须输入</p>
+                <label class="label">应用版本</label>
+                <input
+                    type="text"
+                    class="w-full input validator"
+                    maxlength="20"
+                    pattern=APP_VERSION_PATTERN
+                    placeholder="留空对所有版本生效"
+                    bind:value=app_version
+                />
+                <p class="validator-hint">"版本格式：[>=|>|<=|<|=]主版本[.次版本[.修订号]]"</p>
+                <OpTip content=op_tiper.0 />
+                {move || {
+                    summary
+                        .get()
+                        .map(|summary| {
+                            view! {
+                                <div class="alert alert-success">
+                                    <div>
+                                        <div class="font-bold">
+                                            {format!("新增 {} 项", summary.created.len())}
+                                        </div>
+                                        <div>{summary.created.join(", ")}</div>
+                                        <div class="font-bold">
+                                            {format!("已存在跳过 {} 项", summary.skipped.len())}
+                                        </div>
+                                        <div>{summary.skipped.join(", ")}</div>
+                                    </div>
+                                </div>
+                            }
+                        })
+                }}
+                <button class="mt-4 btn btn-neutral" type="submit">
+                    应用
+                </button>
+            </fieldset>
+        </form>
+    }
+}
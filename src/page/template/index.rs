@@ -0,0 +1,151 @@
+use leptos::{prelude::*, task::spawn_local};
+use tower::tauri_web::prelude::*;
+
+use crate::{
+    model::ConfigTemplateListItem,
+    service::{async_delete_config_template, async_get_config_templates},
+};
+
+use super::*;
+
+#[component]
+pub fn ConfigTemplatePane() -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    let templates_resource = LocalResource::new(move || async_get_config_templates());
+    let current_template = RwSignal::new(None::<ConfigTemplateListItem>);
+    let open_save = RwSignal::new(false);
+    let open_apply = RwSignal::new(false);
+    view! {
+        <div class="flex gap-4">
+            <button
+                class="btn"
+                on:click=move |_| {
+                    templates_resource.refetch();
+                }
+            >
+                刷新
+            </button>
+            <button
+                class="btn"
+                on:click=move |_| {
+                    current_template.set(None);
+                    open_save.set(true);
+                }
+            >
+                新增
+            </button>
+        </div>
+        <table class="table">
+            <thead>
+                <tr>
+                    <th></th>
+                    <th>名称</th>
+                    <th>配置项</th>
+                    <th>备注</th>
+                    <th>创建时间</th>
+                    <th>更新时间</th>
+                    <th>操作</th>
+                </tr>
+            </thead>
+            <tbody>
+                <Suspense fallback=move || {
+                    view! { <span class="self-center loading loading-spinner loading-xl"></span> }
+                }>
+                    {move || {
+                        templates_resource
+                            .to_view(|list| {
+                                list.into_iter()
+                                    .enumerate()
+                                    .map(|(index, template)| {
+                                        let template = template.clone();
+                                        let template_id = template.id;
+                                        let var_names = template
+                                            .items
+                                            .iter()
+                                            .map(|item| item.var_name.clone())
+                                            .collect::<Vec<_>>()
+                                            .join(", ");
+                                        let update_template = template.clone();
+                                        view! {
+                                            <tr>
+                                                <th>{index + 1}</th>
+                                                <td>{template.template_name.clone()}</td>
+                                                <td>
+                                                    <span class="tooltip" data-tip=var_names>
+                                                        {format!("{} 项", template.items.len())}
+                                                    </span>
+                                                </td>
+                                                <td>{template.remark.clone()}</td>
+                                                <td>{from_secs_and_default(template.created)}</td>
+                                                <td>
+                                                    {template
+                                                        .updated
+                                                        .map_or(
+                                                            "".to_string(),
+                                                            |updated| { from_secs_and_default(updated) },
+                                                        )}
+                                                </td>
+                                                <td class="flex gap-4">
+                                                    <button
+                                                        class="btn btn-xs"
+                                                        on:click=move |_| {
+                                                            current_template.set(Some(template.clone()));
+                                                            open_apply.set(true)
+                                                        }
+                                                    >
+                                                        应用
+                                                    </button>
+                                                    <button
+                                                        class="btn btn-xs"
+                                                        on:click=move |_| {
+                                                            current_template.set(Some(update_template.clone()));
+                                                            open_save.set(true)
+                                                        }
+                                                    >
+                                                        修改
+                                                    </button>
+                                                    <ConfirmDialogButton
+                                                        btn="删除"
+                                                        btn_class="btn-xs"
+                                                        title="删除模板"
+                                                        message="确认删除？"
+                                                        on_ok=move || {
+                                                            spawn_local(async move {
+                                                                match async_delete_config_template(template_id).await {
+                                                                    Ok(_) => {
+                                                                        templates_resource.refetch();
+                                                                        app_state.success("删除成功")
+                                                                    }
+                                                                    Err(err) => app_state.error(err.to_string()),
+                                                                }
+                                                            });
+                                                        }
+                                                    />
+                                                </td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view()
+                                    .into_any()
+                            })
+                    }}
+                </Suspense>
+            </tbody>
+        </table>
+        <Show when=move || { current_template.get().is_none() && open_save.get() }>
+            <Dialog open=open_save title="新增模板">
+                <SaveTemplateForm open=open_save templates_resource template=current_template />
+            </Dialog>
+        </Show>
+        <Show when=move || { current_template.get().is_some() && open_save.get() }>
+            <Dialog open=open_save title="修改模板">
+                <SaveTemplateForm open=open_save templates_resource template=current_template />
+            </Dialog>
+        </Show>
+        <Show when=move || { current_template.get().is_some() && open_apply.get() }>
+            <Dialog open=open_apply title="应用模板">
+                <ApplyTemplateForm template=current_template />
+            </Dialog>
+        </Show>
+    }
+}
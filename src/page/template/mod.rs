@@ -0,0 +1,8 @@
+mod index;
+pub use index::*;
+
+mod save_template;
+pub use save_template::*;
+
+mod apply_template;
+pub use apply_template::*;
@@ -0,0 +1,271 @@
+use leptos::{prelude::*, task::spawn_local};
+use tower::{
+    tauri_web::prelude::*,
+    web_model::{OpTipData, OpTipType},
+};
+
+use crate::{
+    model::{ConfigTemplateItem, ConfigTemplateListItem, ConfigValueType, SaveConfigTemplateReq},
+    service::async_save_config_template,
+};
+
+#[component]
+pub(crate) fn SaveTemplateForm(
+    open: RwSignal<bool>,
+    templates_resource: WebResult<Vec<ConfigTemplateListItem>>,
+    template: RwSignal<Option<ConfigTemplateListItem>>,
+) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+    let form = SaveTemplateFormData::new(template.get_untracked());
+    let op_tiper = OpTiper::new();
+    view! {
+        <form on:submit=move |ev| {
+            ev.prevent_default();
+            let req = match form.to_req() {
+                Ok(req) => req,
+                Err(content) => {
+                    op_tiper
+                        .0
+                        .set(
+                            Some(OpTipData {
+                                typ: OpTipType::Warning,
+                                class: "".into(),
+                                content,
+                            }),
+                        );
+                    return;
+                }
+            };
+            spawn_local(async move {
+                let resp = async_save_config_template(req).await;
+                tip_or(
+                    resp,
+                    op_tiper.0,
+                    |_| {
+                        templates_resource.refetch();
+                        open.set(false);
+                        app_state.success("保存模板成功。");
+                    },
+                );
+            });
+        }>
+            <fieldset class="p-4 w-full fieldset">
+                <label class="label">模板名称</label>
+                <input
+                    type="text"
+                    class="w-full input validator"
+                    minlength="1"
+                    maxlength="50"
+                    required
+                    bind:value=form.template_name
+                />
+                <p class="validator-hint">必须输入</p>
+                <label class="label">配置项</label>
+                <table class="table table-xs">
+                    <thead>
+                        <tr>
+                            <th>名称</th>
+                            <th>默认值</th>
+                            <th>类型</th>
+                            <th>备注</th>
+                            <th></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {move || {
+                            form.items
+                                .get()
+                                .into_iter()
+                                .enumerate()
+                                .map(|(index, item)| {
+                                    view! {
+                                        <tr>
+                                            <td>
+                                                <input
+                                                    type="text"
+                                                    class="input input-xs validator"
+                                                    minlength="1"
+                                                    maxlength="50"
+                                                    required
+                                                    bind:value=item.var_name
+                                                />
+                                            </td>
+                                            <td>
+                                                <input
+                                                    type="text"
+                                                    class="input input-xs"
+                                                    maxlength="50"
+                                                    bind:value=item.var_value
+                                                />
+                                            </td>
+                                            <td>
+                                                <select
+                                                    class="select select-xs"
+                                                    on:change:target=move |ev| {
+                                                        item.value_type
+                                                            .set(ev.target().value().try_into().unwrap_or_default());
+                                                    }
+                                                    prop:value=move || item.value_type.get().to_string()
+                                                >
+                                                    {ConfigValueType::all()
+                                                        .into_iter()
+                                                        .map(|value_type| {
+                                                            view! {
+                                                                <option value=value_type.to_string()>
+                                                                    {value_type.label()}
+                                                                </option>
+                                                            }
+                                                        })
+                                                        .collect_view()}
+                                                </select>
+                                            </td>
+                                            <td>
+                                                <input
+                                                    type="text"
+                                                    class="input input-xs"
+                                                    maxlength="500"
+                                                    bind:value=item.remark
+                                                />
+                                            </td>
+                                            <td>
+                                                <button
+                                                    class="btn btn-xs btn-ghost"
+                                                    type="button"
+                                                    on:click=move |_| {
+                                                        form.items
+                                                            .update(|items| {
+                                                                items.remove(index);
+                                                            });
+                                                    }
+                                                >
+                                                    删除
+                                                </button>
+                                            </td>
+                                        </tr>
+                                    }
+                                })
+                                .collect_view()
+                        }}
+                    </tbody>
+                </table>
+                <button
+                    class="btn btn-xs"
+                    type="button"
+                    on:click=move |_| {
+                        form.items.update(|items| items.push(TemplateItemRow::new(None)));
+                    }
+                >
+                    添加配置项
+                </button>
+                <label class="mt-4 mb-2 label">备注</label>
+                <textarea
+                    class="w-full textarea validator"
+                    maxlength="500"
+                    on:input:target=move |ev| { form.remark.set(ev.target().value()) }
+                >
+                    {move || form.remark.get_untracked()}
+                </textarea>
+                <OpTip content=op_tiper.0 />
+                <button class="mt-4 btn btn-neutral" type="submit">
+                    确定
+                </button>
+            </fieldset>
+        </form>
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TemplateItemRow {
+    var_name: RwSignal<String>,
+    var_value: RwSignal<String>,
+    value_type: RwSignal<ConfigValueType>,
+    remark: RwSignal<String>,
+}
+impl TemplateItemRow {
+    fn new(item: Option<ConfigTemplateItem>) -> Self {
+        let item = item.unwrap_or(ConfigTemplateItem {
+            var_name: "".to_string(),
+            var_value: "".to_string(),
+            value_type: ConfigValueType::String,
+            remark: None,
+        });
+        Self {
+            var_name: RwSignal::new(item.var_name),
+            var_value: RwSignal::new(item.var_value),
+            value_type: RwSignal::new(item.value_type),
+            remark: RwSignal::new(item.remark.unwrap_or_default()),
+        }
+    }
+    fn to_item(&self) -> Result<ConfigTemplateItem, String> {
+        let var_name = self.var_name.get_untracked().trim().to_string();
+        let var_value = self.var_value.get_untracked();
+        let value_type = self.value_type.get_untracked();
+        if !value_type.validate(&var_value) {
+            return Err(format!(
+                "{}的默认值不是有效的{}",
+                var_name,
+                value_type.label()
+            ));
+        }
+        Ok(ConfigTemplateItem {
+            var_name,
+            var_value,
+            value_type,
+            remark: str_trim_to_option(self.remark.get_untracked()),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SaveTemplateFormData {
+    id: Option<i32>,
+    template_name: RwSignal<String>,
+    items: RwSignal<Vec<TemplateItemRow>>,
+    remark: RwSignal<String>,
+}
+impl SaveTemplateFormData {
+    fn new(template: Option<ConfigTemplateListItem>) -> Self {
+        match template {
+            Some(template) => Self {
+                id: Some(template.id),
+                template_name: RwSignal::new(template.template_name),
+                items: RwSignal::new(
+                    template
+                        .items
+                        .into_iter()
+                        .map(|item| TemplateItemRow::new(Some(item)))
+                        .collect(),
+                ),
+                remark: RwSignal::new(template.remark.unwrap_or_default()),
+            },
+            None => Self {
+                id: None,
+                template_name: RwSignal::new("".to_string()),
+                items: RwSignal::new(vec![TemplateItemRow::new(None)]),
+                remark: RwSignal::new("".to_string()),
+            },
+        }
+    }
+    fn to_req(&self) -> Result<SaveConfigTemplateReq, String> {
+        let items = self
+            .items
+            .get_untracked()
+            .iter()
+            .map(|item| item.to_item())
+            .collect::<Result<Vec<_>, String>>()?;
+        if items.is_empty() {
+            return Err("模板至少包含一个配置项".to_string());
+        }
+        let mut var_names = items.iter().map(|item| &item.var_name).collect::<Vec<_>>();
+        var_names.sort();
+        if let Some(duplicate) = var_names.windows(2).find(|pair| pair[0] == pair[1]) {
+            return Err(format!("配置项{}重复", duplicate[0]));
+        }
+        Ok(SaveConfigTemplateReq {
+            id: self.id,
+            template_name: self.template_name.get_untracked().trim().to_string(),
+            items,
+            remark: str_trim_to_option(self.remark.get_untracked()),
+        })
+    }
+}
@@ -1,13 +1,9 @@
 use std::collections::HashSet;
 
 use leptos::{prelude::*, task::spawn_local};
-use tower::{
-    management_model::{ManagementResource, SetUserAppsReq},
-    tauri_web::prelude::*,
-    web::component::Checkbox,
-};
+use tower::{management_model::SetUserAppsReq, tauri_web::prelude::*, web::component::Checkbox};
 
-use crate::service::async_get_user_apps;
+use crate::service::{async_get_user_apps, async_set_user_apps};
 
 #[component]
 pub fn AppPane(user_id: RwSignal<Option<String>>) -> impl IntoView {
@@ -37,10 +33,7 @@ pub fn AppPane(user_id: RwSignal<Option<String>>) -> impl IntoView {
                     disabled=move || user_id.get().is_none()
                     on:click=move |_| {
                         spawn_local(async move {
-                            let resp: Result<(), ApiError> = async_http_and(
-                                    App::TowerServer,
-                                    "put",
-                                    &ManagementResource::UserApp.path(ApiMethod::Batch),
+                            let resp = async_set_user_apps(
                                     SetUserAppsReq {
                                         user_id: user_id.get_untracked().unwrap(),
                                         app_ids: checked_items
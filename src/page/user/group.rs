@@ -1,15 +1,11 @@
 use std::collections::HashSet;
 
 use leptos::{prelude::*, task::spawn_local};
-use tower::{
-    management_model::{ManagementResource, SetUserGroupsReq},
-    tauri_web::prelude::*,
-    web::component::Checkbox,
-};
+use tower::{management_model::SetUserGroupsReq, tauri_web::prelude::*, web::component::Checkbox};
 
 use crate::{
     page::AddGroupForm,
-    service::{async_get_groups, async_get_user_groups},
+    service::{async_get_groups, async_get_user_groups, async_set_user_groups},
 };
 
 #[component]
@@ -50,10 +46,7 @@ pub fn GroupPane(user_id: RwSignal<Option<String>>) -> impl IntoView {
                     disabled=move || user_id.get().is_none()
                     on:click=move |_| {
                         spawn_local(async move {
-                            let resp: Result<(), ApiError> = async_http_and(
-                                    App::TowerServer,
-                                    "put",
-                                    &ManagementResource::UserGroup.path(ApiMethod::Batch),
+                            let resp = async_set_user_groups(
                                     SetUserGroupsReq {
                                         user_id: user_id.get_untracked().unwrap(),
                                         group_ids: checked_items
@@ -1,8 +1,9 @@
 use super::*;
-use crate::service::async_get_paged_users;
+use crate::service::{async_get_paged_users, async_reset_password, async_set_user_status};
+use crate::{model::AuditResourceType, page::ActivityPane};
 use leptos::{prelude::*, task::spawn_local};
 use tower::{
-    management_model::{ManagementResource, ResetPasswordReq, SetStatusReq, UserListReq},
+    management_model::{ResetPasswordReq, SetStatusReq, UserListReq},
     tauri_web::prelude::*,
     web::{common::date::from_secs_and, component::Pagination},
 };
@@ -87,10 +88,7 @@ pub fn UserPane() -> impl IntoView {
                                                 class="btn btn-xs"
                                                 on:click=move |_| {
                                                     spawn_local(async move {
-                                                        let resp: Result<(), ApiError> = async_http_and(
-                                                                App::TowerServer,
-                                                                "post",
-                                                                &ManagementResource::User.path("/reset_password"),
+                                                        let resp = async_reset_password(
                                                                 ResetPasswordReq {
                                                                     user_id: user_id.get_untracked(),
                                                                 },
@@ -112,10 +110,7 @@ pub fn UserPane() -> impl IntoView {
                                                 class="btn btn-xs"
                                                 on:click=move |_| {
                                                     spawn_local(async move {
-                                                        let resp: Result<(), ApiError> = async_http_and(
-                                                                App::TowerServer,
-                                                                "post",
-                                                                &ManagementResource::User.path("/status"),
+                                                        let resp = async_set_user_status(
                                                                 SetStatusReq {
                                                                     user_id: user_id.get_untracked(),
                                                                     status,
@@ -150,6 +145,16 @@ pub fn UserPane() -> impl IntoView {
                 <RolePane user_id=current_user_id />
                 <GroupPane user_id=current_user_id />
                 <VipLevelPane user_id=current_user_id />
+                <ActivityPane
+                    resource_types=vec![
+                        AuditResourceType::User,
+                        AuditResourceType::UserApp,
+                        AuditResourceType::UserRole,
+                        AuditResourceType::UserGroup,
+                        AuditResourceType::UserVipLevel,
+                    ]
+                    target_id=current_user_id
+                />
             }
             .into_any()
         })
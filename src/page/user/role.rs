@@ -1,15 +1,11 @@
 use std::collections::HashSet;
 
 use leptos::{logging::log, prelude::*, task::spawn_local};
-use tower::{
-    management_model::{ManagementResource, SetUserRolesReq},
-    tauri_web::prelude::*,
-    web::component::Checkbox,
-};
+use tower::{management_model::SetUserRolesReq, tauri_web::prelude::*, web::component::Checkbox};
 
 use crate::{
     page::AddRoleForm,
-    service::{async_get_roles, async_get_user_roles},
+    service::{async_get_roles, async_get_user_roles, async_set_user_roles},
 };
 
 #[component]
@@ -50,10 +46,7 @@ pub fn RolePane(user_id: RwSignal<Option<String>>) -> impl IntoView {
                     disabled=move || user_id.get().is_none()
                     on:click=move |_| {
                         spawn_local(async move {
-                            let resp: Result<(), ApiError> = async_http_and(
-                                    App::TowerServer,
-                                    "put",
-                                    &ManagementResource::UserRole.path(ApiMethod::Batch),
+                            let resp = async_set_user_roles(
                                     SetUserRolesReq {
                                         user_id: user_id.get_untracked().unwrap(),
                                         role_ids: checked_items
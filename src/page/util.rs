@@ -1 +1,62 @@
+use wasm_bindgen::JsValue;
 
+/// `<input type="datetime-local">`的值（本地时间）转为秒
+pub fn datetime_local_to_secs(value: &str) -> Option<i64> {
+    if value.trim().is_empty() {
+        return None;
+    }
+    let millis = js_sys::Date::new(&JsValue::from_str(value)).get_time();
+    (!millis.is_nan()).then(|| (millis / 1000.0) as i64)
+}
+
+/// 秒转为`<input type="datetime-local">`的值（本地时间）
+pub fn secs_to_datetime_local(secs: i64) -> String {
+    let date = js_sys::Date::new(&JsValue::from_f64(secs as f64 * 1000.0));
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}",
+        date.get_full_year(),
+        date.get_month() + 1,
+        date.get_date(),
+        date.get_hours(),
+        date.get_minutes()
+    )
+}
+
+/// 拖拽排序：把`source`移到`target`之前，返回新的顺序；`target`不在列表中时移到末尾
+pub fn move_before<T: Clone + PartialEq>(list: &[T], source: &T, target: &T) -> Vec<T> {
+    if source == target {
+        return list.to_vec();
+    }
+    let mut sorted: Vec<T> = list.iter().filter(|it| *it != source).cloned().collect();
+    let index = sorted
+        .iter()
+        .position(|it| it == target)
+        .unwrap_or(sorted.len());
+    sorted.insert(index, source.clone());
+    sorted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn move_before_moves_up_and_down() {
+        let list = vec![1, 2, 3, 4];
+        assert_eq!(move_before(&list, &4, &2), vec![1, 4, 2, 3]);
+        assert_eq!(move_before(&list, &1, &4), vec![2, 3, 1, 4]);
+        assert_eq!(move_before(&list, &2, &1), vec![2, 1, 3, 4]);
+    }
+
+    #[test]
+    fn move_before_onto_itself_keeps_order() {
+        let list = vec![1, 2, 3];
+        assert_eq!(move_before(&list, &2, &2), list);
+    }
+
+    #[test]
+    fn move_before_unknown_target_appends() {
+        let list = vec![1, 2, 3];
+        assert_eq!(move_before(&list, &1, &9), vec![2, 3, 1]);
+    }
+}
@@ -0,0 +1,37 @@
+use tower::{management_model::ManagementResource, tauri_web::prelude::*};
+
+use crate::model::{
+    ConfigChangeListItem, ConfigChangeListReq, ReviewConfigChangeReq, SubmitConfigChangeReq,
+};
+
+pub async fn async_get_config_changes(
+    req: ConfigChangeListReq,
+) -> Result<Vec<ConfigChangeListItem>, ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Config.path("/change/list"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_submit_config_change(req: SubmitConfigChangeReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Config.path("/change/submit"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_review_config_change(req: ReviewConfigChangeReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Config.path("/change/review"),
+        req,
+    )
+    .await
+}
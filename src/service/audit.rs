@@ -0,0 +1,38 @@
+use tower::{common::App, tauri_web::prelude::*};
+
+use crate::model::{AuditListItem, AuditListReq, AuditResourceType};
+
+/// 审计接口跨配置、字典、用户等资源，不归属某一种管理资源
+const AUDIT_PATH: &str = "/management/audit";
+
+/// 审计记录由服务端在变更的同一事务中写入，客户端只读
+pub async fn async_get_audits(req: AuditListReq) -> Result<Vec<AuditListItem>, ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &format!("{}/list", AUDIT_PATH),
+        req,
+    )
+    .await
+}
+
+/// 单个对象的操作记录：`resource_types`按`target_id`、`child_types`按`parent_id`匹配，一次查询
+pub async fn async_get_activities(
+    resource_types: Vec<AuditResourceType>,
+    child_types: Vec<AuditResourceType>,
+    target_id: Option<String>,
+) -> Result<Vec<AuditListItem>, ApiError> {
+    if target_id.is_none() {
+        return Ok(vec![]);
+    }
+    async_get_audits(AuditListReq {
+        resource_types,
+        target_id,
+        child_types,
+        limit: ACTIVITY_LIMIT,
+        ..Default::default()
+    })
+    .await
+}
+
+const ACTIVITY_LIMIT: u32 = 50;
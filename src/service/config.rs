@@ -1,12 +1,17 @@
 use tower::{
     common::{ApiMethod, App},
-    management_model::{
-        AddConfigReq, ConfigListItem, ConfigListReq, ManagementResource, UpdateConfigReq,
-    },
+    management_model::ManagementResource,
     tauri_web::prelude::*,
 };
 
-pub async fn async_get_configs(req: ConfigListReq) -> Result<Vec<ConfigListItem>, ApiError> {
+use crate::model::{
+    AddConfigReqExt, BatchDeleteConfigReq, BatchUpdateConfigReq, CloneConfigsReq, CloneConfigsResp,
+    ConfigConsumerItem, ConfigExportItem, ConfigListItemExt, ConfigListReqExt, ConfigSearchReq,
+    ExportConfigReq, ResolveConfigReq, ResolvedConfigItem, RevealConfigReq, RevealConfigResp,
+    SaveScheduledChangeReq, ScheduledChangeListItem, ScheduledChangeListReq, UpdateConfigReqExt,
+};
+
+pub async fn async_get_configs(req: ConfigListReqExt) -> Result<Vec<ConfigListItemExt>, ApiError> {
     async_http_and(
         App::TowerServer,
         "post",
@@ -14,9 +19,14 @@ pub async fn async_get_configs(req: ConfigListReq) -> Result<Vec<ConfigListItem>
         req,
     )
     .await
+    .map(|list: Vec<ConfigListItemExt>| {
+        list.into_iter()
+            .map(ConfigListItemExt::mask_secret)
+            .collect()
+    })
 }
 
-pub async fn async_add_config(req: AddConfigReq) -> Result<(), ApiError> {
+pub async fn async_add_config(req: AddConfigReqExt) -> Result<(), ApiError> {
     async_http_and(
         App::TowerServer,
         "post",
@@ -26,7 +36,7 @@ pub async fn async_add_config(req: AddConfigReq) -> Result<(), ApiError> {
     .await
 }
 
-pub async fn async_update_config(req: UpdateConfigReq) -> Result<(), ApiError> {
+pub async fn async_update_config(req: UpdateConfigReqExt) -> Result<(), ApiError> {
     async_http_and(
         App::TowerServer,
         "post",
@@ -36,6 +46,7 @@ pub async fn async_update_config(req: UpdateConfigReq) -> Result<(), ApiError> {
     .await
 }
 
+/// 受保护配置由服务端拒绝删除
 pub async fn async_delete_config(id: i32) -> Result<(), ApiError> {
     async_http_and(
         App::TowerServer,
@@ -45,3 +56,129 @@ pub async fn async_delete_config(id: i32) -> Result<(), ApiError> {
     )
     .await
 }
+
+pub async fn async_reveal_config(req: RevealConfigReq) -> Result<RevealConfigResp, ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Config.path("/reveal"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_export_configs(req: ExportConfigReq) -> Result<Vec<ConfigExportItem>, ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Config.path("/export"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_resolve_configs(
+    req: ResolveConfigReq,
+) -> Result<Vec<ResolvedConfigItem>, ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Config.path("/resolve"),
+        req,
+    )
+    .await
+    .map(|list: Vec<ResolvedConfigItem>| {
+        list.into_iter()
+            .map(ResolvedConfigItem::mask_secret)
+            .collect()
+    })
+}
+
+pub async fn async_get_scheduled_changes(
+    req: ScheduledChangeListReq,
+) -> Result<Vec<ScheduledChangeListItem>, ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Config.path("/schedule/list"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_save_scheduled_change(req: SaveScheduledChangeReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Config.path("/schedule/save"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_cancel_scheduled_change(id: i32) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "delete",
+        &ManagementResource::Config.path(format!("/schedule/{}", id)),
+        empty_req(),
+    )
+    .await
+}
+
+pub async fn async_search_configs(
+    req: ConfigSearchReq,
+) -> Result<Vec<ConfigListItemExt>, ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Config.path("/search"),
+        req,
+    )
+    .await
+    .map(|list: Vec<ConfigListItemExt>| {
+        list.into_iter()
+            .map(ConfigListItemExt::mask_secret)
+            .collect()
+    })
+}
+
+pub async fn async_batch_update_configs(req: BatchUpdateConfigReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "put",
+        &ManagementResource::Config.path(ApiMethod::Batch),
+        req,
+    )
+    .await
+}
+
+pub async fn async_batch_delete_configs(req: BatchDeleteConfigReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Config.path("/batch_delete"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_clone_configs(req: CloneConfigsReq) -> Result<CloneConfigsResp, ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Config.path("/clone"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_get_config_consumers(id: i32) -> Result<Vec<ConfigConsumerItem>, ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "get",
+        &ManagementResource::Config.path(format!("/consumer/{}", id)),
+        empty_req(),
+    )
+    .await
+}
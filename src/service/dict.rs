@@ -1,10 +1,24 @@
+use std::collections::HashMap;
+
 use tower::{
     common::{ApiMethod, App},
-    management_model::{AddDictReq, DictListItem, ManagementResource, UpdateDictReq},
+    management_model::{DictListItem, ManagementResource},
     tauri_web::prelude::*,
 };
 
-pub async fn async_get_dicts() -> Result<DictListItem, ApiError> {
+use crate::{
+    model::{
+        AddDictDependencyReq, AddDictReqExt, DeleteDictReq, DictExtField, DictListItemExt,
+        DictTransferDict, DictTransferItem, DictUsage, DictUsageReq, DictVersionDetail,
+        DictVersionListItem, DictVersionListReq, DictVersionReq, LocaleLabels, PublishDictReq,
+        RestoreDictReq, RollbackDictReq, SortDictsReq, UpdateDictReqExt,
+    },
+    service::async_get_dict_items,
+};
+
+/// 平铺的字典列表，含树结构中没有的排序、多语言名称和扩展字段定义；
+/// 同时需要这些字段时只查询一次
+pub async fn async_get_dict_list() -> Result<Vec<DictListItemExt>, ApiError> {
     async_http_and(
         App::TowerServer,
         "get",
@@ -12,10 +26,40 @@ pub async fn async_get_dicts() -> Result<DictListItem, ApiError> {
         empty_req(),
     )
     .await
-    .map(|dicts| list_to_tree(dicts))
 }
 
-pub async fn async_add_dict(req: AddDictReq) -> Result<(), ApiError> {
+pub async fn async_get_dicts() -> Result<DictListItem, ApiError> {
+    async_get_dict_list().await.map(|dicts| {
+        // 建树后逐层排序，不依赖`list_to_tree`保留输入顺序
+        fn sort_children(dict: &mut DictListItem, sorts: &HashMap<String, i32>) {
+            dict.children
+                .sort_by_key(|child| sorts.get(&child.dict_code).copied().unwrap_or_default());
+            for child in dict.children.iter_mut() {
+                sort_children(child, sorts);
+            }
+        }
+        let sorts: HashMap<String, i32> = dicts
+            .iter()
+            .map(|dict| (dict.base.dict_code.clone(), dict.sort))
+            .collect();
+        let mut root = list_to_tree(dicts.into_iter().map(|dict| dict.base).collect());
+        sort_children(&mut root, &sorts);
+        root
+    })
+}
+
+/// 字典的扩展字段定义，树结构中不含该字段
+pub async fn async_get_dict_ext_schema(dict_code: String) -> Result<Vec<DictExtField>, ApiError> {
+    async_get_dict_list().await.map(|dicts| {
+        dicts
+            .into_iter()
+            .find(|dict| dict.base.dict_code == dict_code)
+            .map(|dict| dict.ext_schema)
+            .unwrap_or_default()
+    })
+}
+
+pub async fn async_add_dict(req: AddDictReqExt) -> Result<(), ApiError> {
     async_http_and(
         App::TowerServer,
         "post",
@@ -25,7 +69,7 @@ pub async fn async_add_dict(req: AddDictReq) -> Result<(), ApiError> {
     .await
 }
 
-pub async fn async_update_dict(req: UpdateDictReq) -> Result<(), ApiError> {
+pub async fn async_update_dict(req: UpdateDictReqExt) -> Result<(), ApiError> {
     async_http_and(
         App::TowerServer,
         "post",
@@ -34,3 +78,148 @@ pub async fn async_update_dict(req: UpdateDictReq) -> Result<(), ApiError> {
     )
     .await
 }
+
+pub async fn async_delete_dict(req: DeleteDictReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Dict.path("/delete"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_get_deleted_dicts() -> Result<Vec<DictListItem>, ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "get",
+        &ManagementResource::Dict.path("/deleted"),
+        empty_req(),
+    )
+    .await
+}
+
+pub async fn async_restore_dict(req: RestoreDictReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Dict.path("/restore"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_sort_dicts(req: SortDictsReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Dict.path("/sort"),
+        req,
+    )
+    .await
+}
+
+/// `root`及其全部下级字典连同字典项，按树的顺序平铺，用于导出和导入对比
+pub async fn async_get_dict_subtree(root: DictListItem) -> Result<Vec<DictTransferDict>, ApiError> {
+    fn flatten(dict: &DictListItem, result: &mut Vec<DictListItem>) {
+        result.push(dict.clone());
+        for child in dict.children.iter() {
+            flatten(child, result);
+        }
+    }
+    let mut list = vec![];
+    flatten(&root, &mut list);
+    let mut labels: HashMap<String, LocaleLabels> = async_get_dict_list()
+        .await?
+        .into_iter()
+        .map(|dict| (dict.base.dict_code, dict.labels))
+        .collect();
+    let mut dicts = vec![];
+    for dict in list {
+        let items = async_get_dict_items(Some(dict.dict_code.clone())).await?;
+        dicts.push(DictTransferDict {
+            labels: Some(labels.remove(&dict.dict_code).unwrap_or_default()),
+            dict_code: dict.dict_code,
+            dict_name: dict.dict_name,
+            parent_code: dict.parent_code,
+            public: dict.public,
+            status: dict.status,
+            remark: dict.remark,
+            items: DictTransferItem::from_items(items),
+        });
+    }
+    Ok(dicts)
+}
+
+pub async fn async_get_dict_usage(req: DictUsageReq) -> Result<DictUsage, ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "get",
+        &ManagementResource::Dict.path("/usage"),
+        req,
+    )
+    .await
+    .map(DictUsage::mask_secret)
+}
+
+pub async fn async_add_dict_dependency(req: AddDictDependencyReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Dict.path("/dependency"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_delete_dict_dependency(id: i32) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "delete",
+        &ManagementResource::Dict.path(format!("/dependency/{}", id)),
+        empty_req(),
+    )
+    .await
+}
+
+pub async fn async_get_dict_versions(
+    req: DictVersionListReq,
+) -> Result<Vec<DictVersionListItem>, ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "get",
+        &ManagementResource::Dict.path("/version/list"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_get_dict_version(req: DictVersionReq) -> Result<DictVersionDetail, ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "get",
+        &ManagementResource::Dict.path("/version"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_publish_dict(req: PublishDictReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Dict.path("/publish"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_rollback_dict(req: RollbackDictReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Dict.path("/rollback"),
+        req,
+    )
+    .await
+}
@@ -1,14 +1,17 @@
 use tower::{
     common::{ApiMethod, App},
-    management_model::{
-        AddDictItemReq, DictItemListItem, DictItemListReq, ManagementResource, UpdateDictItemReq,
-    },
+    management_model::{DictItemListItem, DictItemListReq, ManagementResource},
     tauri_web::prelude::*,
 };
 
+use crate::model::{
+    AddDictItemReqExt, DictItemListItemExt, RestoreDictItemReq, SortDictItemsReq,
+    UpdateDictItemReqExt,
+};
+
 pub async fn async_get_dict_items(
     dict_code: Option<String>,
-) -> Result<Vec<DictItemListItem>, ApiError> {
+) -> Result<Vec<DictItemListItemExt>, ApiError> {
     if dict_code.is_none() {
         return Ok(vec![]);
     }
@@ -21,9 +24,13 @@ pub async fn async_get_dict_items(
         },
     )
     .await
+    .map(|mut items: Vec<DictItemListItemExt>| {
+        items.sort_by_key(|item| item.sort);
+        items
+    })
 }
 
-pub async fn async_add_dict_item(req: AddDictItemReq) -> Result<(), ApiError> {
+pub async fn async_add_dict_item(req: AddDictItemReqExt) -> Result<(), ApiError> {
     async_http_and(
         App::TowerServer,
         "post",
@@ -33,7 +40,7 @@ pub async fn async_add_dict_item(req: AddDictItemReq) -> Result<(), ApiError> {
     .await
 }
 
-pub async fn async_update_dict_item(req: UpdateDictItemReq) -> Result<(), ApiError> {
+pub async fn async_update_dict_item(req: UpdateDictItemReqExt) -> Result<(), ApiError> {
     async_http_and(
         App::TowerServer,
         "post",
@@ -42,3 +49,50 @@ pub async fn async_update_dict_item(req: UpdateDictItemReq) -> Result<(), ApiErr
     )
     .await
 }
+
+pub async fn async_delete_dict_item(id: i32) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "delete",
+        &ManagementResource::DictItem.path(ApiMethod::DeleteById(id.to_string())),
+        empty_req(),
+    )
+    .await
+}
+
+pub async fn async_get_deleted_dict_items(
+    dict_code: Option<String>,
+) -> Result<Vec<DictItemListItem>, ApiError> {
+    if dict_code.is_none() {
+        return Ok(vec![]);
+    }
+    async_http_and(
+        App::TowerServer,
+        "get",
+        &ManagementResource::DictItem.path("/deleted"),
+        DictItemListReq {
+            dict_code: dict_code.unwrap(),
+        },
+    )
+    .await
+}
+
+pub async fn async_restore_dict_item(req: RestoreDictItemReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::DictItem.path("/restore"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_sort_dict_items(req: SortDictItemsReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::DictItem.path("/sort"),
+        req,
+    )
+    .await
+}
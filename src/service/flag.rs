@@ -0,0 +1,43 @@
+use tower::{management_model::ManagementResource, tauri_web::prelude::*};
+
+use crate::model::{EvaluateFlagReq, EvaluateFlagResp, FlagListItem, FlagListReq, SaveFlagReq};
+
+pub async fn async_get_flags(req: FlagListReq) -> Result<Vec<FlagListItem>, ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Config.path("/flag/list"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_save_flag(req: SaveFlagReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Config.path("/flag/save"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_delete_flag(id: i32) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "delete",
+        &ManagementResource::Config.path(format!("/flag/{}", id)),
+        empty_req(),
+    )
+    .await
+}
+
+pub async fn async_evaluate_flag(req: EvaluateFlagReq) -> Result<EvaluateFlagResp, ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Config.path("/flag/evaluate"),
+        req,
+    )
+    .await
+}
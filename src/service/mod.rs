@@ -27,3 +27,15 @@ pub use dict::*;
 
 mod dict_item;
 pub use dict_item::*;
+
+mod flag;
+pub use flag::*;
+
+mod approval;
+pub use approval::*;
+
+mod template;
+pub use template::*;
+
+mod audit;
+pub use audit::*;
@@ -0,0 +1,47 @@
+use tower::{management_model::ManagementResource, tauri_web::prelude::*};
+
+use crate::model::{
+    ApplyConfigTemplateReq, ApplyConfigTemplateResp, ConfigTemplateListItem, SaveConfigTemplateReq,
+};
+
+pub async fn async_get_config_templates() -> Result<Vec<ConfigTemplateListItem>, ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "get",
+        &ManagementResource::Config.path("/template/list"),
+        empty_req(),
+    )
+    .await
+}
+
+pub async fn async_save_config_template(req: SaveConfigTemplateReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Config.path("/template/save"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_delete_config_template(id: i32) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "delete",
+        &ManagementResource::Config.path(format!("/template/{}", id)),
+        empty_req(),
+    )
+    .await
+}
+
+pub async fn async_apply_config_template(
+    req: ApplyConfigTemplateReq,
+) -> Result<ApplyConfigTemplateResp, ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::Config.path("/template/apply"),
+        req,
+    )
+    .await
+}
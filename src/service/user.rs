@@ -1,5 +1,7 @@
 use tower::{
-    management_model::{ManagementResource, UserListReq, UserListResp},
+    management_model::{
+        ManagementResource, ResetPasswordReq, SetStatusReq, UserListReq, UserListResp,
+    },
     tauri_web::prelude::*,
 };
 
@@ -14,3 +16,23 @@ pub async fn async_get_paged_users(
     )
     .await
 }
+
+pub async fn async_set_user_status(req: SetStatusReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::User.path("/status"),
+        req,
+    )
+    .await
+}
+
+pub async fn async_reset_password(req: ResetPasswordReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "post",
+        &ManagementResource::User.path("/reset_password"),
+        req,
+    )
+    .await
+}
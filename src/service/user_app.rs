@@ -1,5 +1,5 @@
 use tower::{
-    management_model::{GetUserAppsReq, ManagementResource},
+    management_model::{GetUserAppsReq, ManagementResource, SetUserAppsReq},
     tauri_web::prelude::*,
 };
 
@@ -16,3 +16,13 @@ pub async fn async_get_user_apps(user_id: Option<String>) -> Result<Vec<String>,
     )
     .await
 }
+
+pub async fn async_set_user_apps(req: SetUserAppsReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "put",
+        &ManagementResource::UserApp.path(ApiMethod::Batch),
+        req,
+    )
+    .await
+}
@@ -1,5 +1,5 @@
 use tower::{
-    management_model::{GetUserRolesReq, ManagementResource},
+    management_model::{GetUserRolesReq, ManagementResource, SetUserGroupsReq},
     tauri_web::prelude::*,
 };
 
@@ -16,3 +16,13 @@ pub async fn async_get_user_groups(user_id: Option<String>) -> Result<Vec<String
     )
     .await
 }
+
+pub async fn async_set_user_groups(req: SetUserGroupsReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "put",
+        &ManagementResource::UserGroup.path(ApiMethod::Batch),
+        req,
+    )
+    .await
+}
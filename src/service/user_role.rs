@@ -1,5 +1,5 @@
 use tower::{
-    management_model::{GetUserRolesReq, ManagementResource},
+    management_model::{GetUserRolesReq, ManagementResource, SetUserRolesReq},
     tauri_web::prelude::*,
 };
 
@@ -16,3 +16,13 @@ pub async fn async_get_user_roles(user_id: Option<String>) -> Result<Vec<String>
     )
     .await
 }
+
+pub async fn async_set_user_roles(req: SetUserRolesReq) -> Result<(), ApiError> {
+    async_http_and(
+        App::TowerServer,
+        "put",
+        &ManagementResource::UserRole.path(ApiMethod::Batch),
+        req,
+    )
+    .await
+}